@@ -3,13 +3,44 @@ use serde::Deserialize;
 use serde_json::Value;
 use std::borrow::Cow;
 use std::env;
+use std::fmt;
+use std::sync::OnceLock;
+use std::sync::atomic::{AtomicBool, Ordering};
 
 const LINEAR_API_URL: &str = "https://api.linear.app/graphql";
 
+static DRY_RUN: AtomicBool = AtomicBool::new(false);
+
+/// Returned by [`mutate_linear`] in dry-run mode after the payload was printed.
+#[derive(Debug)]
+pub struct DryRun;
+
+impl fmt::Display for DryRun {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "dry run: mutation not sent")
+    }
+}
+
+impl std::error::Error for DryRun {}
+
+pub fn set_dry_run(enabled: bool) {
+    DRY_RUN.store(enabled, Ordering::Relaxed);
+}
+
+pub fn is_dry_run() -> bool {
+    DRY_RUN.load(Ordering::Relaxed)
+}
+
 pub fn get_api_key() -> Result<String, String> {
     env::var("LINEAR_API_KEY").map_err(|_| "LINEAR_API_KEY environment variable not set".to_string())
 }
 
+/// One HTTP client per process so that batch commands reuse connections.
+fn http_client() -> &'static reqwest::Client {
+    static CLIENT: OnceLock<reqwest::Client> = OnceLock::new();
+    CLIENT.get_or_init(reqwest::Client::new)
+}
+
 async fn query_linear_internal<T>(
     query: &str,
     variables: Option<Value>,
@@ -19,8 +50,8 @@ where
     T: for<'de> Deserialize<'de>,
 {
     let api_key = get_api_key()?;
-    
-    let client = reqwest::Client::new();
+
+    let client = http_client();
     let request = GraphQLRequest {
         query: Cow::Borrowed(query),
         variables,
@@ -34,7 +65,23 @@ where
         .send()
         .await?;
 
-    let graphql_response: GraphQLResponse<T> = response.json().await?;
+    let status = response.status();
+    let body: Value = response
+        .json()
+        .await
+        .map_err(|e| format!("HTTP {}: {}", status, e))?;
+
+    if let Some(errors) = body.get("errors").and_then(Value::as_array)
+        && !errors.is_empty()
+    {
+        let messages: Vec<&str> = errors
+            .iter()
+            .filter_map(|error| error.get("message").and_then(Value::as_str))
+            .collect();
+        return Err(messages.join("; ").into());
+    }
+
+    let graphql_response: GraphQLResponse<T> = serde_json::from_value(body)?;
     Ok(graphql_response.data)
 }
 
@@ -45,6 +92,81 @@ where
     query_linear_internal(query, variables, LINEAR_API_URL).await
 }
 
+/// PUTs a file to the signed URL returned by Linear's `fileUpload` mutation,
+/// with the headers that came back alongside it.
+pub async fn upload_file(
+    upload_url: &str,
+    headers: &[(String, String)],
+    content_type: &str,
+    bytes: Vec<u8>,
+) -> Result<(), Box<dyn std::error::Error>> {
+    let mut request = http_client()
+        .put(upload_url)
+        .header("Content-Type", content_type)
+        .header("Cache-Control", "public, max-age=31536000");
+    for (key, value) in headers {
+        request = request.header(key.as_str(), value.as_str());
+    }
+
+    let response = request.body(bytes).send().await?;
+    let status = response.status();
+    if !status.is_success() {
+        let body = response.text().await.unwrap_or_default();
+        return Err(format!("upload failed: HTTP {}: {}", status, body.trim()).into());
+    }
+    Ok(())
+}
+
+/// Downloads a private asset such as an `uploads.linear.app` file, which needs
+/// the API key. Returns the body and its content type.
+pub async fn download_asset(url: &str) -> Result<(Vec<u8>, Option<String>), Box<dyn std::error::Error>> {
+    let api_key = get_api_key()?;
+    fetch_bytes(url, &api_key).await
+}
+
+async fn fetch_bytes(url: &str, api_key: &str) -> Result<(Vec<u8>, Option<String>), Box<dyn std::error::Error>> {
+    let response = http_client().get(url).header("Authorization", api_key).send().await?;
+    let status = response.status();
+    if !status.is_success() {
+        return Err(format!("download failed: HTTP {}: {}", status, url).into());
+    }
+    let content_type = response
+        .headers()
+        .get(reqwest::header::CONTENT_TYPE)
+        .and_then(|value| value.to_str().ok())
+        .map(String::from);
+    Ok((response.bytes().await?.to_vec(), content_type))
+}
+
+/// Sends a mutation, or in dry-run mode prints the exact query and variables
+/// and fails with [`DryRun`] so callers stop before acting on a result.
+pub async fn mutate_linear<T>(mutation: &str, variables: Option<Value>) -> Result<T, Box<dyn std::error::Error>>
+where
+    T: for<'de> Deserialize<'de>,
+{
+    if is_dry_run() {
+        println!("{}", dry_run_payload(mutation, variables.as_ref()));
+        return Err(Box::new(DryRun));
+    }
+    query_linear(mutation, variables).await
+}
+
+fn dry_run_payload(mutation: &str, variables: Option<&Value>) -> String {
+    let lines: Vec<&str> = mutation.lines().filter(|line| !line.trim().is_empty()).collect();
+    let indent = lines
+        .iter()
+        .map(|line| line.len() - line.trim_start().len())
+        .min()
+        .unwrap_or(0);
+    let query: Vec<&str> = lines.iter().map(|line| &line[indent..]).collect();
+
+    let variables = variables
+        .and_then(|variables| serde_json::to_string_pretty(variables).ok())
+        .unwrap_or_else(|| "null".to_string());
+
+    format!("{}\nvariables: {}", query.join("\n"), variables)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -87,6 +209,89 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_dry_run_payload_dedents_query() {
+        let mutation = r#"
+            mutation IssueCreate($input: IssueCreateInput!) {
+                issueCreate(input: $input) { success }
+            }
+        "#;
+        let variables = json!({"input": {"title": "Test"}});
+        let payload = dry_run_payload(mutation, Some(&variables));
+        assert_eq!(
+            payload,
+            "mutation IssueCreate($input: IssueCreateInput!) {\n    issueCreate(input: $input) { success }\n}\nvariables: {\n  \"input\": {\n    \"title\": \"Test\"\n  }\n}"
+        );
+    }
+
+    #[test]
+    fn test_dry_run_payload_without_variables() {
+        let payload = dry_run_payload("mutation { x }", None);
+        assert_eq!(payload, "mutation { x }\nvariables: null");
+    }
+
+    #[test]
+    fn test_dry_run_error_message() {
+        let error: Box<dyn std::error::Error> = Box::new(DryRun);
+        assert!(error.is::<DryRun>());
+        assert_eq!(error.to_string(), "dry run: mutation not sent");
+    }
+
+    #[tokio::test]
+    async fn test_upload_file_sends_signed_headers() {
+        let mock_server = MockServer::start().await;
+        Mock::given(method("PUT"))
+            .and(path("/upload"))
+            .and(header("Content-Type", "image/png"))
+            .and(header("x-goog-meta-id", "abc"))
+            .respond_with(ResponseTemplate::new(200))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        let headers = vec![("x-goog-meta-id".to_string(), "abc".to_string())];
+        let url = format!("{}/upload", mock_server.uri());
+        upload_file(&url, &headers, "image/png", b"png".to_vec()).await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_upload_file_reports_http_errors() {
+        let mock_server = MockServer::start().await;
+        Mock::given(method("PUT"))
+            .respond_with(ResponseTemplate::new(403).set_body_string("SignatureDoesNotMatch"))
+            .mount(&mock_server)
+            .await;
+
+        let error = upload_file(&mock_server.uri(), &[], "text/plain", Vec::new())
+            .await
+            .unwrap_err();
+        assert!(error.to_string().contains("403"));
+        assert!(error.to_string().contains("SignatureDoesNotMatch"));
+    }
+
+    #[tokio::test]
+    async fn test_fetch_bytes_sends_api_key() {
+        let mock_server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/org/asset"))
+            .and(header("Authorization", "key-1"))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .insert_header("Content-Type", "image/png")
+                    .set_body_bytes(b"png".to_vec()),
+            )
+            .mount(&mock_server)
+            .await;
+
+        let url = format!("{}/org/asset", mock_server.uri());
+        let (bytes, content_type) = fetch_bytes(&url, "key-1").await.unwrap();
+        assert_eq!(bytes, b"png");
+        assert_eq!(content_type.as_deref(), Some("image/png"));
+
+        let missing = format!("{}/org/missing", mock_server.uri());
+        assert!(fetch_bytes(&missing, "key-1").await.unwrap_err().to_string().contains("404"));
+    }
+
     #[tokio::test]
     async fn test_query_linear_teams() {
         let mock_server = MockServer::start().await;
@@ -270,6 +475,47 @@ mod tests {
         }
     }
 
+    #[tokio::test]
+    async fn test_query_linear_graphql_errors() {
+        let mock_server = MockServer::start().await;
+        unsafe {
+            std::env::set_var("LINEAR_API_KEY", "test-key");
+        }
+
+        let response_body = json!({
+            "data": null,
+            "errors": [
+                {"message": "Entity not found: Team"},
+                {"message": "Argument Validation Error"}
+            ]
+        });
+
+        Mock::given(method("POST"))
+            .and(path("/graphql"))
+            .respond_with(ResponseTemplate::new(400).set_body_json(&response_body))
+            .mount(&mock_server)
+            .await;
+
+        unsafe {
+            std::env::set_var("LINEAR_API_KEY", "test-key");
+        }
+
+        let url = format!("{}/graphql", mock_server.uri());
+        let result: Result<TeamsResponse, _> = query_linear_internal(
+            "query Teams { teams { nodes { id name } } }",
+            None,
+            &url,
+        )
+        .await;
+
+        let error_msg = format!("{}", result.err().unwrap());
+        assert_eq!(error_msg, "Entity not found: Team; Argument Validation Error");
+
+        unsafe {
+            std::env::remove_var("LINEAR_API_KEY");
+        }
+    }
+
     #[tokio::test]
     async fn test_query_linear_missing_api_key() {
         let mock_server = MockServer::start().await;
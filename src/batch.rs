@@ -0,0 +1,220 @@
+//! Input parsing for `batch create`: JSONL objects or CSV rows, each turned
+//! into an [`IssueSpec`]. Rows that fail to parse are reported individually
+//! so the rest of the batch can still go through.
+
+use crate::types::IssueSpec;
+use serde_json::{Map, Value};
+
+pub const FIELDS: &[&str] = &[
+    "title",
+    "description",
+    "team",
+    "project",
+    "labels",
+    "priority",
+    "assignee",
+    "subscribers",
+    "milestone",
+    "parent",
+    "estimate",
+    "idempotency_key",
+];
+
+/// A parsed row with the 1-based input line it starts on.
+pub type Row = (usize, Result<IssueSpec, String>);
+
+/// Parses one JSON object per line; blank lines are skipped.
+pub fn parse_jsonl(input: &str) -> Vec<Row> {
+    input
+        .lines()
+        .enumerate()
+        .filter(|(_, line)| !line.trim().is_empty())
+        .map(|(index, line)| {
+            let spec = serde_json::from_str::<IssueSpec>(line).map_err(|e| e.to_string());
+            (index + 1, spec)
+        })
+        .collect()
+}
+
+/// Parses CSV with a header row. Columns are matched to issue fields by name
+/// (case-insensitive) or through `COLUMN=FIELD` mappings; other columns are
+/// ignored and reported back so the caller can warn about them.
+pub fn parse_csv(input: &str, mappings: &[String]) -> Result<(Vec<Row>, Vec<String>), String> {
+    let mut records = parse_csv_records(input)?.into_iter();
+    let (_, header) = records.next().ok_or("CSV input is empty")?;
+
+    let mut columns: Vec<Option<&str>> = Vec::with_capacity(header.len());
+    let mut ignored = Vec::new();
+    for name in &header {
+        let field = column_field(name, mappings)?;
+        if field.is_none() {
+            ignored.push(name.clone());
+        }
+        columns.push(field);
+    }
+
+    let rows = records
+        .map(|(line, record)| {
+            let mut object = Map::new();
+            for (value, field) in record.into_iter().zip(&columns) {
+                if let Some(field) = field
+                    && !value.trim().is_empty()
+                {
+                    object.insert(field.to_string(), Value::String(value));
+                }
+            }
+            let spec = serde_json::from_value::<IssueSpec>(Value::Object(object))
+                .map_err(|e| e.to_string());
+            (line, spec)
+        })
+        .collect();
+
+    Ok((rows, ignored))
+}
+
+fn column_field(column: &str, mappings: &[String]) -> Result<Option<&'static str>, String> {
+    for mapping in mappings {
+        let (from, to) = mapping
+            .split_once('=')
+            .ok_or_else(|| format!("invalid mapping: {} (expected COLUMN=FIELD)", mapping))?;
+        if from.trim().eq_ignore_ascii_case(column.trim()) {
+            return FIELDS
+                .iter()
+                .find(|field| field.eq_ignore_ascii_case(to.trim()))
+                .map(|field| Some(*field))
+                .ok_or_else(|| format!("unknown field in mapping: {}", to));
+        }
+    }
+
+    Ok(FIELDS
+        .iter()
+        .find(|field| field.eq_ignore_ascii_case(column.trim()))
+        .copied())
+}
+
+/// RFC 4180 style records: quoted fields may contain commas, newlines and `""`.
+/// Each record comes with the 1-based line it starts on; blank lines are skipped.
+fn parse_csv_records(input: &str) -> Result<Vec<(usize, Vec<String>)>, String> {
+    let mut records = Vec::new();
+    let mut record = Vec::new();
+    let mut field = String::new();
+    let mut in_quotes = false;
+    let mut chars = input.chars().peekable();
+    let mut line = 1;
+    let mut start = 1;
+
+    while let Some(c) = chars.next() {
+        if c == '\n' {
+            line += 1;
+        }
+        match (c, in_quotes) {
+            ('"', true) if chars.peek() == Some(&'"') => {
+                chars.next();
+                field.push('"');
+            }
+            ('"', true) => in_quotes = false,
+            ('"', false) if field.is_empty() => in_quotes = true,
+            (',', false) => record.push(std::mem::take(&mut field)),
+            ('\r', false) if chars.peek() == Some(&'\n') => {}
+            ('\n', false) => {
+                record.push(std::mem::take(&mut field));
+                if record.iter().any(|value| !value.is_empty()) {
+                    records.push((start, std::mem::take(&mut record)));
+                } else {
+                    record.clear();
+                }
+                start = line;
+            }
+            (c, _) => field.push(c),
+        }
+    }
+
+    if in_quotes {
+        return Err("CSV input ends inside a quoted field".to_string());
+    }
+    if !field.is_empty() || !record.is_empty() {
+        record.push(field);
+        records.push((start, record));
+    }
+
+    Ok(records)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_jsonl_rows() {
+        let input = "{\"title\": \"One\", \"team\": \"ENG\"}\n\n{\"title\": \"Two\"}\n";
+        let rows = parse_jsonl(input);
+        assert_eq!(rows.len(), 2);
+        assert_eq!(rows[0].0, 1);
+        assert_eq!(rows[0].1.as_ref().unwrap().title, Some("One".to_string()));
+        // Row numbers are input lines, so the blank line is counted.
+        assert_eq!(rows[1].0, 3);
+        assert_eq!(rows[1].1.as_ref().unwrap().title, Some("Two".to_string()));
+    }
+
+    #[test]
+    fn test_parse_jsonl_keeps_going_after_bad_row() {
+        let rows = parse_jsonl("not json\n{\"title\": \"Ok\"}\n");
+        assert!(rows[0].1.is_err());
+        assert!(rows[1].1.is_ok());
+    }
+
+    #[test]
+    fn test_parse_csv_with_header_names() {
+        let input = "Title,Team,Labels\nFix login,ENG,\"bug, auth\"\n";
+        let (rows, ignored) = parse_csv(input, &[]).unwrap();
+        assert!(ignored.is_empty());
+        let spec = rows[0].1.as_ref().unwrap();
+        assert_eq!(spec.title, Some("Fix login".to_string()));
+        assert_eq!(spec.team, Some("ENG".to_string()));
+        assert_eq!(spec.labels, vec!["bug", "auth"]);
+    }
+
+    #[test]
+    fn test_parse_csv_with_mappings() {
+        let input = "Summary,Squad,Notes\nFix login,ENG,internal\n";
+        let mappings = vec!["Summary=title".to_string(), "squad=team".to_string()];
+        let (rows, ignored) = parse_csv(input, &mappings).unwrap();
+        assert_eq!(ignored, vec!["Notes"]);
+        let spec = rows[0].1.as_ref().unwrap();
+        assert_eq!(spec.title, Some("Fix login".to_string()));
+        assert_eq!(spec.team, Some("ENG".to_string()));
+    }
+
+    #[test]
+    fn test_parse_csv_rows_use_input_lines() {
+        let input = "Title,Description\nOne,\n\nTwo,\"multi\nline\"\nThree,\n";
+        let (rows, _) = parse_csv(input, &[]).unwrap();
+        let lines: Vec<usize> = rows.iter().map(|(line, _)| *line).collect();
+        assert_eq!(lines, vec![2, 4, 6]);
+        assert_eq!(rows[2].1.as_ref().unwrap().title, Some("Three".to_string()));
+    }
+
+    #[test]
+    fn test_parse_csv_invalid_mapping() {
+        assert!(parse_csv("a\n1\n", &["a=nope".to_string()]).is_err());
+        assert!(parse_csv("a\n1\n", &["a".to_string()]).is_err());
+    }
+
+    #[test]
+    fn test_parse_csv_records_quoting() {
+        let records = parse_csv_records("a,b\r\n\"x, \"\"y\"\"\",\"multi\nline\"\n\nlast,row").unwrap();
+        assert_eq!(
+            records,
+            vec![
+                (1, vec!["a".to_string(), "b".to_string()]),
+                (2, vec!["x, \"y\"".to_string(), "multi\nline".to_string()]),
+                (5, vec!["last".to_string(), "row".to_string()])
+            ]
+        );
+    }
+
+    #[test]
+    fn test_parse_csv_records_unterminated_quote() {
+        assert!(parse_csv_records("\"open,field\n").is_err());
+    }
+}
@@ -1,4 +1,7 @@
-use clap::{Parser, Subcommand};
+use crate::dedupe::DEFAULT_THRESHOLD;
+use crate::message::MagicWord;
+use clap::{Args, Parser, Subcommand, ValueEnum};
+use std::path::PathBuf;
 
 #[derive(Parser)]
 #[command(name = "linearite")]
@@ -10,38 +13,842 @@ EXAMPLES:
 
   # List all projects to get a project ID
   linearite list-projects
+  linearite list-projects --team ENG --state started --lead me
+
+  # See a team's workflow
+  linearite list-states --team ENG
+
+  # Find users to assign or subscribe
+  linearite list-users
+  linearite list-members --team ENG
 
   # Create an issue with team ID and description
   linearite create "Fix bug in API" --team-id abc123 --description "The API is broken"
 
   # Create an issue with team ID, description, and project ID
   linearite create "Add new feature" --team-id abc123 --description "Implement feature X" --project-id xyz789
+
+  # Refuse to file an issue that looks like an open one (or comment on it instead)
+  linearite create "Login fails on Safari" --team-id abc123 --dedupe
+  linearite create "Login fails on Safari" --team-id abc123 --dedupe --on-duplicate comment
+
+  # Safe to retry: a second run with the same key returns the existing issue
+  linearite create "Deploy failed" --team-id abc123 --idempotency-key deploy-1234
+
+  # Work on an issue: switch to its git branch, start it and take it
+  linearite checkout ENG-123 --start --assign-me
+  linearite create "Fix bug in API" --team-id abc123 --checkout
+
+  # Inside a branch like kxzk/eng-123-fix-api the issue can be omitted
+  linearite view
+  linearite view ENG-123 --download-assets ./assets
+  linearite comment --body "Fixed in the latest push"
+  linearite close
+
+  # Commit messages and PR bodies that Linear links back to the issue
+  git commit -m "$(linearite git commit-msg)"
+  gh pr create --title "$(linearite git commit-msg | head -1)" --body "$(linearite git pr-body)"
+  linearite git install-hook
+
+  # Check the API key and see what is on your plate
+  linearite whoami
+  linearite my
+  linearite my created --team ENG
+
+  # Plan a project
+  linearite project create "API v2" --team ENG --lead me --target-date 2026-12-01
+  linearite project update "API v2" --state started
+  linearite project view "API v2"
+  linearite project post-update "API v2" --health atRisk --body-file update.md
+
+  # List issues with a query
+  linearite list assignee:me state:started priority:>=high label:bug updated:<7d
+  linearite list 'project:"API v2" due:<2w'
+
+  # Saved queries from ~/.config/linearite/config.toml, and Linear custom views
+  linearite q triage
+  linearite view-list "My Bugs"
+
+  # Groom the backlog: preview, confirm, then update every match
+  linearite bulk update --filter 'team:ENG label:stale state:backlog' --set state=canceled --add-label archived
+
+  # Clean up: archive or trash issues (asks first unless --yes)
+  linearite archive ENG-123 ENG-124 --yes
+  linearite delete ENG-125
+  linearite restore ENG-125
+
+  # Attach screenshots, logs and links to an issue
+  linearite attach ENG-123 ./screenshot.png
+  linearite attach ENG-123 ./app.log --to description
+  linearite attach ENG-123 --url https://github.com/acme/api/pull/42 --title "Fix PR"
+
+  # Structure a project with milestones and move issues into them
+  linearite milestone create Beta --project "API v2" --target-date 2026-11-01
+  linearite milestone list --project "API v2"
+  linearite update ENG-123 --milestone Beta
+
+  # Keep design specs next to the project
+  linearite doc create --project "API v2" --title "Design spec" --content-file spec.md
+  linearite doc export https://linear.app/acme/document/design-spec-1a2b3c -o spec.md
+
+  # Roadmap: initiatives and the projects behind them
+  linearite list-initiatives
+  linearite initiative view "Self-serve onboarding"
+  linearite initiative add-project "Self-serve onboarding" "API v2"
+
+  # Look for existing context before filing something new
+  linearite search "payment timeout" --team ENG --state started
+  linearite search "onboarding" --kind documents
+
+  # Turn untracked TODO comments into issues and tag them with the new identifier
+  linearite todos scan --create --team-id ENG --label tech-debt --rewrite
+  linearite todos check
+
+  # Show the exact mutation and variables without creating anything
+  linearite create "Fix bug in API" --team-id abc123 --dry-run
+
+  # Create (or update) issues from markdown files with front matter
+  linearite create --from-file ticket.md
+  linearite create --from-file docs/tickets/
+
+  # Create many issues from JSONL (or CSV) on stdin, one result line per row
+  linearite batch create < issues.jsonl
+  linearite batch create --format csv --map Summary=title --team-id ENG < issues.csv
 "#)]
 pub struct Cli {
     #[command(subcommand)]
     pub command: Commands,
+    /// Resolve and validate everything, print the mutations that would be sent, but don't send them
+    #[arg(long, global = true)]
+    pub dry_run: bool,
 }
 
 #[derive(Subcommand)]
 pub enum Commands {
     /// Create a new issue in Linear
-    Create {
-        /// Title of the issue
-        title: String,
-        /// Description of the issue
-        #[arg(short, long)]
-        description: Option<String>,
-        /// Team ID to associate the issue with
-        #[arg(short = 't', long = "team-id", required = true)]
-        team_id: String,
-        /// Project ID to associate the issue with
-        #[arg(short = 'p', long = "project-id")]
-        project_id: Option<String>,
-    },
-    /// List all teams (name + id)
+    Create(CreateArgs),
+    /// Show an issue (defaults to the issue in the current git branch)
+    View(ViewArgs),
+    /// Comment on an issue (defaults to the issue in the current git branch)
+    Comment(CommentArgs),
+    /// Mark an issue as done (defaults to the issue in the current git branch)
+    Close(CloseArgs),
+    /// Create or switch to the git branch for an issue
+    Checkout(CheckoutArgs),
+    /// Commit messages, PR descriptions and hooks linked to Linear issues
+    Git {
+        #[command(subcommand)]
+        command: GitCommands,
+    },
+    /// Show the user, organization and teams behind the API key
+    Whoami,
+    /// List your open issues grouped by state
+    My(MyArgs),
+    /// Set an issue's project milestone
+    Update(UpdateArgs),
+    /// Archive issues
+    Archive(IssuesActionArgs),
+    /// Restore archived issues
+    Unarchive(IssuesActionArgs),
+    /// Move issues to the trash
+    Delete(IssuesActionArgs),
+    /// Restore issues from the trash
+    Restore(IssuesActionArgs),
+    /// Upload a file to an issue, or attach a link
+    Attach(AttachArgs),
+    /// List and create project milestones
+    Milestone {
+        #[command(subcommand)]
+        command: MilestoneCommands,
+    },
+    /// Create, read, update and export Linear documents
+    Doc {
+        #[command(subcommand)]
+        command: DocCommands,
+    },
+    /// View initiatives and link projects to them
+    Initiative {
+        #[command(subcommand)]
+        command: InitiativeCommands,
+    },
+    /// Create, update, archive and view projects
+    Project {
+        #[command(subcommand)]
+        command: ProjectCommands,
+    },
+    /// List issues matching a query, e.g. `assignee:me state:started`
+    List(ListArgs),
+    /// Run a saved query from the config file (lists saved queries without a name)
+    Q(SavedQueryArgs),
+    /// List the issues of a Linear custom view (lists custom views without a name)
+    ViewList(ViewListArgs),
+    /// Full-text search over issues, documents or projects
+    Search(SearchArgs),
+    /// Find TODO/FIXME/HACK comments and track them as issues
+    Todos {
+        #[command(subcommand)]
+        command: TodosCommands,
+    },
+    /// Create or process many issues at once
+    Batch {
+        #[command(subcommand)]
+        command: BatchCommands,
+    },
+    /// Change many existing issues at once
+    Bulk {
+        #[command(subcommand)]
+        command: BulkCommands,
+    },
+    /// List all teams (name, id, key, members, cycles, triage, description)
     ListTeams,
-    /// List all projects (name + id)
-    ListProjects,
+    /// List projects (name, id, state, lead, target date, progress, teams, url)
+    ListProjects(ListProjectsArgs),
+    /// List a team's workflow states (name, type, color, position, id)
+    ListStates {
+        /// Team key, name or ID
+        #[arg(short = 't', long)]
+        team: String,
+    },
+    /// List initiatives (name, status, owner, target date, id)
+    ListInitiatives,
+    /// List workspace users (name, display name, email, flags, id)
+    ListUsers,
+    /// List the members of a team
+    ListMembers {
+        /// Team key, name or ID
+        #[arg(short = 't', long)]
+        team: String,
+    },
+}
+
+#[derive(Args)]
+pub struct CreateArgs {
+    /// Title of the issue
+    #[arg(required_unless_present = "from_file")]
+    pub title: Option<String>,
+    /// Description of the issue
+    #[arg(short, long)]
+    pub description: Option<String>,
+    /// Team key, name, or ID to associate the issue with (default team for --from-file)
+    #[arg(short = 't', long = "team-id", required_unless_present = "from_file")]
+    pub team_id: Option<String>,
+    /// Project name or ID to associate the issue with (default project for --from-file)
+    #[arg(short = 'p', long = "project-id")]
+    pub project_id: Option<String>,
+    /// Markdown file, or directory of markdown files, with YAML front matter
+    /// (team, project, milestone, labels, priority, assignee, subscribers, parent, estimate)
+    #[arg(short = 'f', long = "from-file", conflicts_with_all = ["title", "description"])]
+    pub from_file: Option<PathBuf>,
+    /// Check the team's open issues for similar titles before creating
+    #[arg(long, conflicts_with = "from_file")]
+    pub dedupe: bool,
+    /// What to do when --dedupe finds a likely duplicate
+    #[arg(long, value_enum, default_value_t = OnDuplicate::Abort, requires = "dedupe")]
+    pub on_duplicate: OnDuplicate,
+    /// Create the issue even when --dedupe finds likely duplicates
+    #[arg(long, requires = "dedupe")]
+    pub force: bool,
+    /// Minimum title similarity (0-1) for --dedupe to treat an issue as a duplicate
+    #[arg(long, default_value_t = DEFAULT_THRESHOLD, requires = "dedupe")]
+    pub dedupe_threshold: f64,
+    /// Create the issue only once per key; retries with the same key return the existing issue
+    #[arg(long, value_name = "KEY", conflicts_with = "from_file")]
+    pub idempotency_key: Option<String>,
+    /// Assignee (`me`, name, display name, email or ID)
+    #[arg(short = 'a', long, conflicts_with = "from_file")]
+    pub assignee: Option<String>,
+    /// Project milestone name or ID (needs --project-id)
+    #[arg(long, requires = "project_id", conflicts_with = "from_file")]
+    pub milestone: Option<String>,
+    /// Subscriber (`me`, name, display name, email or ID; repeatable)
+    #[arg(long = "subscriber", conflicts_with = "from_file")]
+    pub subscribers: Vec<String>,
+    /// Create and switch to the issue's git branch after creating it
+    #[arg(long, conflicts_with = "from_file")]
+    pub checkout: bool,
+}
+
+#[derive(Args)]
+pub struct ViewArgs {
+    /// Issue identifier, e.g. ENG-123 (default: from the current git branch)
+    pub issue: Option<String>,
+    /// Download uploaded images and files into this directory and link to the local copies
+    #[arg(long, value_name = "DIR")]
+    pub download_assets: Option<PathBuf>,
+}
+
+#[derive(Args)]
+pub struct CommentArgs {
+    /// Issue identifier, e.g. ENG-123 (default: from the current git branch)
+    pub issue: Option<String>,
+    /// Comment text (markdown)
+    #[arg(short, long, required_unless_present = "body_file")]
+    pub body: Option<String>,
+    /// Read the comment from a file (`-` for stdin)
+    #[arg(long, conflicts_with = "body")]
+    pub body_file: Option<PathBuf>,
+}
+
+#[derive(Args)]
+pub struct CloseArgs {
+    /// Issue identifier, e.g. ENG-123 (default: from the current git branch)
+    pub issue: Option<String>,
+    /// Cancel the issue instead of completing it
+    #[arg(long)]
+    pub canceled: bool,
+}
+
+#[derive(Args)]
+pub struct CheckoutArgs {
+    /// Issue identifier, e.g. ENG-123
+    pub issue: String,
+    /// Move the issue to the team's first started state (e.g. "In Progress")
+    #[arg(long)]
+    pub start: bool,
+    /// Assign the issue to yourself
+    #[arg(long)]
+    pub assign_me: bool,
+}
+
+#[derive(Args)]
+pub struct ListProjectsArgs {
+    /// Only projects of this team (key, name or ID)
+    #[arg(short = 't', long)]
+    pub team: Option<String>,
+    /// Only projects in this state, e.g. planned, started, paused, completed
+    #[arg(short = 's', long)]
+    pub state: Option<String>,
+    /// Only projects led by this user (`me`, name, email or ID)
+    #[arg(long)]
+    pub lead: Option<String>,
+}
+
+#[derive(Args)]
+pub struct UpdateArgs {
+    /// Issue identifier, e.g. ENG-123 (default: from the current git branch)
+    pub issue: Option<String>,
+    /// Milestone of the issue's project, by name or ID
+    #[arg(long)]
+    pub milestone: String,
+}
+
+#[derive(Args)]
+pub struct IssuesActionArgs {
+    /// Issue identifiers, e.g. ENG-123 ENG-124
+    #[arg(required = true)]
+    pub issues: Vec<String>,
+    /// Don't ask for confirmation
+    #[arg(short = 'y', long)]
+    pub yes: bool,
+}
+
+#[derive(Args)]
+pub struct AttachArgs {
+    /// Issue identifier, e.g. ENG-123
+    pub issue: String,
+    /// File to upload
+    #[arg(required_unless_present = "url")]
+    pub file: Option<PathBuf>,
+    /// Attach a link instead of uploading a file
+    #[arg(long, conflicts_with = "file")]
+    pub url: Option<String>,
+    /// Link title, or the text shown for an uploaded file
+    #[arg(long)]
+    pub title: Option<String>,
+    /// Where an uploaded file is embedded
+    #[arg(long, value_enum, default_value_t = AttachTarget::Comment, conflicts_with = "url")]
+    pub to: AttachTarget,
+    /// Content type of the file (default: guessed from the extension)
+    #[arg(long, conflicts_with = "url")]
+    pub content_type: Option<String>,
+}
+
+#[derive(Clone, Copy, PartialEq, Debug, ValueEnum)]
+pub enum AttachTarget {
+    /// Post a new comment with the file
+    Comment,
+    /// Append the file to the issue description
+    Description,
+}
+
+#[derive(Subcommand)]
+pub enum MilestoneCommands {
+    /// List a project's milestones (name, target date, id)
+    List {
+        /// Project name or ID
+        #[arg(short = 'p', long)]
+        project: String,
+    },
+    /// Create a milestone in a project
+    Create(MilestoneCreateArgs),
+}
+
+#[derive(Args)]
+pub struct MilestoneCreateArgs {
+    /// Name of the milestone
+    pub name: String,
+    /// Project name or ID
+    #[arg(short = 'p', long)]
+    pub project: String,
+    /// Target date (YYYY-MM-DD)
+    #[arg(long)]
+    pub target_date: Option<String>,
+    /// Description of the milestone
+    #[arg(short, long)]
+    pub description: Option<String>,
+}
+
+#[derive(Subcommand)]
+pub enum DocCommands {
+    /// Create a document in a project
+    Create(DocCreateArgs),
+    /// Show a document
+    View {
+        /// Document ID, slug ID or URL
+        document: String,
+    },
+    /// Update a document's title, content or project
+    Update(DocUpdateArgs),
+    /// List documents (title, project, updated at, url, id)
+    List {
+        /// Only documents of this project (name or ID)
+        #[arg(short = 'p', long)]
+        project: Option<String>,
+    },
+    /// Write a document out as markdown
+    Export {
+        /// Document ID, slug ID or URL
+        document: String,
+        /// Output file (default: stdout)
+        #[arg(short, long)]
+        output: Option<PathBuf>,
+    },
+}
+
+#[derive(Args)]
+pub struct DocCreateArgs {
+    /// Title of the document
+    #[arg(long)]
+    pub title: String,
+    /// Project name or ID
+    #[arg(short = 'p', long)]
+    pub project: String,
+    /// Document content (markdown)
+    #[arg(long, conflicts_with = "content_file")]
+    pub content: Option<String>,
+    /// Read the content from a file (`-` for stdin)
+    #[arg(long)]
+    pub content_file: Option<PathBuf>,
+}
+
+#[derive(Args)]
+pub struct DocUpdateArgs {
+    /// Document ID, slug ID or URL
+    pub document: String,
+    /// New title
+    #[arg(long)]
+    pub title: Option<String>,
+    /// Move the document to this project (name or ID)
+    #[arg(short = 'p', long)]
+    pub project: Option<String>,
+    /// New content (markdown)
+    #[arg(long, conflicts_with = "content_file")]
+    pub content: Option<String>,
+    /// Read the new content from a file (`-` for stdin)
+    #[arg(long)]
+    pub content_file: Option<PathBuf>,
+}
+
+#[derive(Subcommand)]
+pub enum InitiativeCommands {
+    /// Show an initiative with its owner, status, target date and projects
+    View {
+        /// Initiative name or ID
+        initiative: String,
+    },
+    /// Link a project to an initiative
+    AddProject {
+        /// Initiative name or ID
+        initiative: String,
+        /// Project name or ID
+        project: String,
+    },
+}
+
+#[derive(Subcommand)]
+pub enum ProjectCommands {
+    /// Create a project
+    Create(ProjectCreateArgs),
+    /// Update a project's fields
+    Update(ProjectUpdateArgs),
+    /// Archive a project
+    Archive {
+        /// Project name or ID
+        project: String,
+    },
+    /// Show a project with its milestones, members, progress and issue counts
+    View {
+        /// Project name or ID
+        project: String,
+    },
+    /// Post a status update (health report) on a project
+    PostUpdate(ProjectPostUpdateArgs),
+    /// Show a project's status updates, newest first
+    Updates {
+        /// Project name or ID
+        project: String,
+        /// Maximum number of updates
+        #[arg(short = 'n', long, default_value_t = 10)]
+        limit: u32,
+    },
+}
+
+#[derive(Args)]
+pub struct ProjectPostUpdateArgs {
+    /// Project name or ID
+    pub project: String,
+    /// Project health
+    #[arg(long, value_enum)]
+    pub health: ProjectHealth,
+    /// Update text (markdown)
+    #[arg(short, long, required_unless_present = "body_file")]
+    pub body: Option<String>,
+    /// Read the update from a file (`-` for stdin)
+    #[arg(long, conflicts_with = "body")]
+    pub body_file: Option<PathBuf>,
+}
+
+#[derive(Clone, Copy, PartialEq, Debug, ValueEnum)]
+pub enum ProjectHealth {
+    #[value(name = "onTrack", alias = "on-track")]
+    OnTrack,
+    #[value(name = "atRisk", alias = "at-risk")]
+    AtRisk,
+    #[value(name = "offTrack", alias = "off-track")]
+    OffTrack,
+}
+
+impl ProjectHealth {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            ProjectHealth::OnTrack => "onTrack",
+            ProjectHealth::AtRisk => "atRisk",
+            ProjectHealth::OffTrack => "offTrack",
+        }
+    }
+}
+
+#[derive(Args)]
+pub struct ProjectCreateArgs {
+    /// Name of the project
+    pub name: String,
+    /// Team key, name or ID (repeatable)
+    #[arg(short = 't', long = "team", required = true)]
+    pub teams: Vec<String>,
+    #[command(flatten)]
+    pub fields: ProjectFieldsArgs,
+}
+
+#[derive(Args)]
+pub struct ProjectUpdateArgs {
+    /// Project name or ID
+    pub project: String,
+    /// New name
+    #[arg(long)]
+    pub name: Option<String>,
+    /// Replace the project's teams (key, name or ID; repeatable)
+    #[arg(short = 't', long = "team")]
+    pub teams: Vec<String>,
+    /// Project state, e.g. planned, started, paused, completed, canceled
+    #[arg(short = 's', long)]
+    pub state: Option<String>,
+    #[command(flatten)]
+    pub fields: ProjectFieldsArgs,
+}
+
+/// Project fields shared by `project create` and `project update`.
+#[derive(Args)]
+pub struct ProjectFieldsArgs {
+    /// Short summary
+    #[arg(short, long)]
+    pub description: Option<String>,
+    /// Project document (markdown)
+    #[arg(long, conflicts_with = "content_file")]
+    pub content: Option<String>,
+    /// Read the project document from a file (`-` for stdin)
+    #[arg(long)]
+    pub content_file: Option<PathBuf>,
+    /// Project lead (`me`, name, email or ID)
+    #[arg(long)]
+    pub lead: Option<String>,
+    /// Start date (YYYY-MM-DD)
+    #[arg(long)]
+    pub start_date: Option<String>,
+    /// Target date (YYYY-MM-DD)
+    #[arg(long)]
+    pub target_date: Option<String>,
+    /// Icon name or emoji
+    #[arg(long)]
+    pub icon: Option<String>,
+    /// Color as hex, e.g. #5e6ad2
+    #[arg(long)]
+    pub color: Option<String>,
+}
+
+#[derive(Args)]
+pub struct MyArgs {
+    /// Which of your issues to list
+    #[arg(value_enum, default_value_t = MyRelation::Assigned)]
+    pub relation: MyRelation,
+    /// Only issues of this team (key, name or ID)
+    #[arg(short = 't', long)]
+    pub team: Option<String>,
+    /// Include completed and canceled issues
+    #[arg(long)]
+    pub all: bool,
+}
+
+#[derive(Clone, Copy, PartialEq, ValueEnum)]
+pub enum MyRelation {
+    /// Issues assigned to you
+    Assigned,
+    /// Issues you created
+    Created,
+    /// Issues you are subscribed to
+    Subscribed,
+}
+
+#[derive(Args)]
+pub struct ListArgs {
+    /// Query terms: team, state, assignee, creator, project, label, priority,
+    /// title, created, updated, completed and due, e.g.
+    /// `state:started priority:>=high updated:<7d project:"API v2"`
+    pub query: Vec<String>,
+    /// Maximum number of issues
+    #[arg(short = 'n', long, default_value_t = 50)]
+    pub limit: usize,
+}
+
+#[derive(Args)]
+pub struct SavedQueryArgs {
+    /// Name of a query under `[queries]` in the config file
+    pub name: Option<String>,
+    /// Extra query terms, e.g. assignee:me
+    #[arg(requires = "name")]
+    pub terms: Vec<String>,
+    /// Maximum number of issues
+    #[arg(short = 'n', long, default_value_t = 50)]
+    pub limit: usize,
+}
+
+#[derive(Args)]
+pub struct ViewListArgs {
+    /// Custom view name or ID
+    pub view: Option<String>,
+    /// Maximum number of issues
+    #[arg(short = 'n', long, default_value_t = 50)]
+    pub limit: usize,
+}
+
+#[derive(Args)]
+pub struct SearchArgs {
+    /// Search terms
+    pub query: String,
+    /// What to search
+    #[arg(long, value_enum, default_value_t = SearchKind::Issues)]
+    pub kind: SearchKind,
+    #[command(flatten)]
+    pub filters: IssueFilterArgs,
+    /// Maximum number of results
+    #[arg(short = 'n', long, default_value_t = 20)]
+    pub limit: u32,
+    /// Include archived results
+    #[arg(long)]
+    pub include_archived: bool,
+}
+
+#[derive(Clone, Copy, PartialEq, ValueEnum)]
+pub enum SearchKind {
+    Issues,
+    Documents,
+    Projects,
+}
+
+/// Issue filters shared by commands that list issues.
+#[derive(Args, Default)]
+pub struct IssueFilterArgs {
+    /// Team key, name or ID
+    #[arg(short = 't', long)]
+    pub team: Option<String>,
+    /// Workflow state name, or a type such as started or completed
+    #[arg(short = 's', long)]
+    pub state: Option<String>,
+    /// Assignee (`me`, name, email or ID)
+    #[arg(short = 'a', long)]
+    pub assignee: Option<String>,
+    /// Project name or ID
+    #[arg(short = 'p', long)]
+    pub project: Option<String>,
+    /// Label name (repeatable; all must match)
+    #[arg(short = 'l', long = "label")]
+    pub labels: Vec<String>,
+}
+
+impl IssueFilterArgs {
+    pub fn is_empty(&self) -> bool {
+        self.team.is_none()
+            && self.state.is_none()
+            && self.assignee.is_none()
+            && self.project.is_none()
+            && self.labels.is_empty()
+    }
+}
+
+#[derive(Clone, Copy, ValueEnum)]
+pub enum OnDuplicate {
+    /// Stop and report the existing issue
+    Abort,
+    /// Add the new report as a comment on the existing issue
+    Comment,
+}
+
+#[derive(Subcommand)]
+pub enum GitCommands {
+    /// Print a conventional commit message for an issue (default: from the current git branch)
+    CommitMsg(CommitMsgArgs),
+    /// Print a pull request description for an issue (default: from the current git branch)
+    PrBody(PrBodyArgs),
+    /// Install a prepare-commit-msg hook that links commits to the branch's issue
+    InstallHook {
+        /// Replace an existing prepare-commit-msg hook
+        #[arg(long)]
+        force: bool,
+    },
+    /// Run by the installed hook
+    #[command(hide = true)]
+    PrepareCommitMsg {
+        file: PathBuf,
+        source: Option<String>,
+        sha: Option<String>,
+    },
+}
+
+#[derive(Args)]
+pub struct CommitMsgArgs {
+    /// Issue identifier, e.g. ENG-123 (default: from the current git branch)
+    pub issue: Option<String>,
+    /// Conventional commit type (default: inferred from labels, e.g. bug -> fix)
+    #[arg(long = "type", value_name = "TYPE")]
+    pub commit_type: Option<String>,
+    /// Magic word Linear uses to link (and possibly close) the issue
+    #[arg(long, value_enum, default_value_t = MagicWord::Fixes)]
+    pub magic_word: MagicWord,
+}
+
+#[derive(Args)]
+pub struct PrBodyArgs {
+    /// Issue identifier, e.g. ENG-123 (default: from the current git branch)
+    pub issue: Option<String>,
+    /// Magic word Linear uses to link (and possibly close) the issue
+    #[arg(long, value_enum, default_value_t = MagicWord::Fixes)]
+    pub magic_word: MagicWord,
+    /// Leave the issue description out of the PR body
+    #[arg(long)]
+    pub no_description: bool,
+}
+
+#[derive(Subcommand)]
+pub enum TodosCommands {
+    /// List TODO/FIXME/HACK comments, optionally filing issues for untracked ones
+    Scan(TodosScanArgs),
+    /// Fail if a TODO(ENG-123) comment references a completed or canceled issue
+    Check {
+        /// Directory to scan
+        #[arg(default_value = ".")]
+        path: PathBuf,
+    },
+}
+
+#[derive(Args)]
+pub struct TodosScanArgs {
+    /// Directory to scan
+    #[arg(default_value = ".")]
+    pub path: PathBuf,
+    /// Create an issue for every untracked comment
+    #[arg(long, requires = "team_id")]
+    pub create: bool,
+    /// Team for created issues
+    #[arg(short = 't', long = "team-id")]
+    pub team_id: Option<String>,
+    /// Project for created issues
+    #[arg(short = 'p', long = "project-id")]
+    pub project_id: Option<String>,
+    /// Label for created issues (repeatable)
+    #[arg(short = 'l', long = "label")]
+    pub labels: Vec<String>,
+    /// Rewrite each comment to TODO(ENG-123) after its issue is created
+    #[arg(long, requires = "create")]
+    pub rewrite: bool,
+}
+
+#[derive(Subcommand)]
+pub enum BatchCommands {
+    /// Create one issue per input row (fields: title, description, team, project,
+    /// labels, priority, assignee, parent, estimate, idempotency_key)
+    Create(BatchCreateArgs),
+}
+
+#[derive(Args)]
+pub struct BatchCreateArgs {
+    /// Input file (reads stdin when omitted or `-`)
+    pub input: Option<PathBuf>,
+    /// Input format
+    #[arg(long, value_enum, default_value_t = BatchFormat::Jsonl)]
+    pub format: BatchFormat,
+    /// Map a CSV column to an issue field, e.g. `--map Summary=title`
+    #[arg(long, value_name = "COLUMN=FIELD")]
+    pub map: Vec<String>,
+    /// Maximum number of issues created at the same time
+    #[arg(short = 'c', long, default_value_t = 4)]
+    pub concurrency: usize,
+    /// Team for rows that don't specify one
+    #[arg(short = 't', long = "team-id")]
+    pub team_id: Option<String>,
+    /// Project for rows that don't specify one
+    #[arg(short = 'p', long = "project-id")]
+    pub project_id: Option<String>,
+}
+
+#[derive(Subcommand)]
+pub enum BulkCommands {
+    /// Update every issue matching a filter, after a preview and confirmation
+    Update(BulkUpdateArgs),
+}
+
+#[derive(Args)]
+pub struct BulkUpdateArgs {
+    /// Issues to update, as a query like `linearite list` takes,
+    /// e.g. 'team:ENG label:stale state:backlog'
+    #[arg(long)]
+    pub filter: String,
+    /// Change a field: state, assignee, priority or project (repeatable)
+    #[arg(long, value_name = "FIELD=VALUE", required_unless_present = "add_labels")]
+    pub set: Vec<String>,
+    /// Add a label (repeatable)
+    #[arg(long = "add-label", value_name = "LABEL")]
+    pub add_labels: Vec<String>,
+    /// Maximum number of issues updated at the same time
+    #[arg(short = 'c', long, default_value_t = 4)]
+    pub concurrency: usize,
+    /// Don't ask for confirmation
+    #[arg(short = 'y', long)]
+    pub yes: bool,
+}
+
+#[derive(Clone, Copy, ValueEnum)]
+pub enum BatchFormat {
+    Jsonl,
+    Csv,
 }
 
 #[cfg(test)]
@@ -50,7 +857,7 @@ mod tests {
 
     #[test]
     fn test_cli_parse_create_command() {
-        let cli = Cli::try_parse_from(&[
+        let cli = Cli::try_parse_from([
             "linearite",
             "create",
             "Test Issue",
@@ -60,14 +867,15 @@ mod tests {
         .unwrap();
 
         match cli.command {
-            Commands::Create {
+            Commands::Create(CreateArgs {
                 title,
                 description,
                 team_id,
                 project_id,
-            } => {
-                assert_eq!(title, "Test Issue");
-                assert_eq!(team_id, "team-123");
+                ..
+            }) => {
+                assert_eq!(title, Some("Test Issue".to_string()));
+                assert_eq!(team_id, Some("team-123".to_string()));
                 assert!(description.is_none());
                 assert!(project_id.is_none());
             }
@@ -77,7 +885,7 @@ mod tests {
 
     #[test]
     fn test_cli_parse_create_with_description() {
-        let cli = Cli::try_parse_from(&[
+        let cli = Cli::try_parse_from([
             "linearite",
             "create",
             "Test Issue",
@@ -89,14 +897,15 @@ mod tests {
         .unwrap();
 
         match cli.command {
-            Commands::Create {
+            Commands::Create(CreateArgs {
                 title,
                 description,
                 team_id,
                 project_id,
-            } => {
-                assert_eq!(title, "Test Issue");
-                assert_eq!(team_id, "team-123");
+                ..
+            }) => {
+                assert_eq!(title, Some("Test Issue".to_string()));
+                assert_eq!(team_id, Some("team-123".to_string()));
                 assert_eq!(description, Some("This is a test description".to_string()));
                 assert!(project_id.is_none());
             }
@@ -106,7 +915,7 @@ mod tests {
 
     #[test]
     fn test_cli_parse_create_with_all_options() {
-        let cli = Cli::try_parse_from(&[
+        let cli = Cli::try_parse_from([
             "linearite",
             "create",
             "Test Issue",
@@ -120,14 +929,15 @@ mod tests {
         .unwrap();
 
         match cli.command {
-            Commands::Create {
+            Commands::Create(CreateArgs {
                 title,
                 description,
                 team_id,
                 project_id,
-            } => {
-                assert_eq!(title, "Test Issue");
-                assert_eq!(team_id, "team-123");
+                ..
+            }) => {
+                assert_eq!(title, Some("Test Issue".to_string()));
+                assert_eq!(team_id, Some("team-123".to_string()));
                 assert_eq!(description, Some("Test description".to_string()));
                 assert_eq!(project_id, Some("proj-456".to_string()));
             }
@@ -137,7 +947,7 @@ mod tests {
 
     #[test]
     fn test_cli_parse_create_with_short_flags() {
-        let cli = Cli::try_parse_from(&[
+        let cli = Cli::try_parse_from([
             "linearite",
             "create",
             "Test Issue",
@@ -151,14 +961,15 @@ mod tests {
         .unwrap();
 
         match cli.command {
-            Commands::Create {
+            Commands::Create(CreateArgs {
                 title,
                 description,
                 team_id,
                 project_id,
-            } => {
-                assert_eq!(title, "Test Issue");
-                assert_eq!(team_id, "team-123");
+                ..
+            }) => {
+                assert_eq!(title, Some("Test Issue".to_string()));
+                assert_eq!(team_id, Some("team-123".to_string()));
                 assert_eq!(description, Some("Test description".to_string()));
                 assert_eq!(project_id, Some("proj-456".to_string()));
             }
@@ -168,13 +979,670 @@ mod tests {
 
     #[test]
     fn test_cli_parse_create_missing_team_id() {
-        let result = Cli::try_parse_from(&["linearite", "create", "Test Issue"]);
+        let result = Cli::try_parse_from(["linearite", "create", "Test Issue"]);
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_cli_parse_create_from_file() {
+        let cli = Cli::try_parse_from(["linearite", "create", "--from-file", "ticket.md"]).unwrap();
+        match cli.command {
+            Commands::Create(args) => {
+                assert_eq!(args.from_file, Some(PathBuf::from("ticket.md")));
+                assert!(args.title.is_none());
+                assert!(args.team_id.is_none());
+            }
+            _ => panic!("Expected Create command"),
+        }
+    }
+
+    #[test]
+    fn test_cli_parse_create_from_file_conflicts_with_title() {
+        let result = Cli::try_parse_from([
+            "linearite",
+            "create",
+            "Test Issue",
+            "--from-file",
+            "ticket.md",
+        ]);
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_cli_parse_create_dedupe() {
+        let cli = Cli::try_parse_from([
+            "linearite",
+            "create",
+            "Test Issue",
+            "-t",
+            "team-123",
+            "--dedupe",
+            "--on-duplicate",
+            "comment",
+        ])
+        .unwrap();
+        match cli.command {
+            Commands::Create(args) => {
+                assert!(args.dedupe);
+                assert!(matches!(args.on_duplicate, OnDuplicate::Comment));
+                assert!(!args.force);
+                assert_eq!(args.dedupe_threshold, DEFAULT_THRESHOLD);
+            }
+            _ => panic!("Expected Create command"),
+        }
+    }
+
+    #[test]
+    fn test_cli_parse_create_idempotency_key() {
+        let cli = Cli::try_parse_from([
+            "linearite",
+            "create",
+            "Test Issue",
+            "-t",
+            "team-123",
+            "--idempotency-key",
+            "deploy-42",
+        ])
+        .unwrap();
+        match cli.command {
+            Commands::Create(args) => {
+                assert_eq!(args.idempotency_key, Some("deploy-42".to_string()));
+            }
+            _ => panic!("Expected Create command"),
+        }
+    }
+
+    #[test]
+    fn test_cli_parse_create_force_requires_dedupe() {
+        let result =
+            Cli::try_parse_from(["linearite", "create", "Test Issue", "-t", "team-123", "--force"]);
         assert!(result.is_err());
     }
 
+    #[test]
+    fn test_cli_parse_batch_create_defaults() {
+        let cli = Cli::try_parse_from(["linearite", "batch", "create"]).unwrap();
+        match cli.command {
+            Commands::Batch {
+                command: BatchCommands::Create(args),
+            } => {
+                assert!(args.input.is_none());
+                assert!(matches!(args.format, BatchFormat::Jsonl));
+                assert_eq!(args.concurrency, 4);
+                assert!(args.map.is_empty());
+            }
+            _ => panic!("Expected Batch Create command"),
+        }
+    }
+
+    #[test]
+    fn test_cli_parse_batch_create_csv() {
+        let cli = Cli::try_parse_from([
+            "linearite",
+            "batch",
+            "create",
+            "issues.csv",
+            "--format",
+            "csv",
+            "--map",
+            "Summary=title",
+            "--map",
+            "Squad=team",
+            "-c",
+            "8",
+        ])
+        .unwrap();
+        match cli.command {
+            Commands::Batch {
+                command: BatchCommands::Create(args),
+            } => {
+                assert_eq!(args.input, Some(PathBuf::from("issues.csv")));
+                assert!(matches!(args.format, BatchFormat::Csv));
+                assert_eq!(args.map, vec!["Summary=title", "Squad=team"]);
+                assert_eq!(args.concurrency, 8);
+            }
+            _ => panic!("Expected Batch Create command"),
+        }
+    }
+
+    #[test]
+    fn test_cli_parse_view_with_and_without_issue() {
+        let cli = Cli::try_parse_from(["linearite", "view", "ENG-123"]).unwrap();
+        match cli.command {
+            Commands::View(args) => assert_eq!(args.issue, Some("ENG-123".to_string())),
+            _ => panic!("Expected View command"),
+        }
+
+        let cli = Cli::try_parse_from(["linearite", "view"]).unwrap();
+        match cli.command {
+            Commands::View(args) => assert!(args.issue.is_none()),
+            _ => panic!("Expected View command"),
+        }
+    }
+
+    #[test]
+    fn test_cli_parse_view_download_assets() {
+        let cli = Cli::try_parse_from(["linearite", "view", "ENG-1", "--download-assets", "assets"]).unwrap();
+        match cli.command {
+            Commands::View(args) => assert_eq!(args.download_assets, Some(PathBuf::from("assets"))),
+            _ => panic!("Expected View command"),
+        }
+    }
+
+    #[test]
+    fn test_cli_parse_comment() {
+        let cli = Cli::try_parse_from(["linearite", "comment", "-b", "Looks good"]).unwrap();
+        match cli.command {
+            Commands::Comment(args) => {
+                assert!(args.issue.is_none());
+                assert_eq!(args.body, Some("Looks good".to_string()));
+            }
+            _ => panic!("Expected Comment command"),
+        }
+
+        assert!(Cli::try_parse_from(["linearite", "comment", "ENG-1"]).is_err());
+    }
+
+    #[test]
+    fn test_cli_parse_close() {
+        let cli = Cli::try_parse_from(["linearite", "close", "ENG-9", "--canceled"]).unwrap();
+        match cli.command {
+            Commands::Close(args) => {
+                assert_eq!(args.issue, Some("ENG-9".to_string()));
+                assert!(args.canceled);
+            }
+            _ => panic!("Expected Close command"),
+        }
+    }
+
+    #[test]
+    fn test_cli_parse_git_commit_msg() {
+        let cli = Cli::try_parse_from([
+            "linearite",
+            "git",
+            "commit-msg",
+            "ENG-1",
+            "--type",
+            "chore",
+            "--magic-word",
+            "part-of",
+        ])
+        .unwrap();
+        match cli.command {
+            Commands::Git {
+                command: GitCommands::CommitMsg(args),
+            } => {
+                assert_eq!(args.issue, Some("ENG-1".to_string()));
+                assert_eq!(args.commit_type, Some("chore".to_string()));
+                assert!(matches!(args.magic_word, MagicWord::PartOf));
+            }
+            _ => panic!("Expected Git CommitMsg command"),
+        }
+    }
+
+    #[test]
+    fn test_cli_parse_git_pr_body_and_hook() {
+        let cli = Cli::try_parse_from(["linearite", "git", "pr-body", "--no-description"]).unwrap();
+        match cli.command {
+            Commands::Git {
+                command: GitCommands::PrBody(args),
+            } => {
+                assert!(args.issue.is_none());
+                assert!(args.no_description);
+                assert!(matches!(args.magic_word, MagicWord::Fixes));
+            }
+            _ => panic!("Expected Git PrBody command"),
+        }
+
+        let cli = Cli::try_parse_from(["linearite", "git", "prepare-commit-msg", ".git/COMMIT_EDITMSG", "message"])
+            .unwrap();
+        match cli.command {
+            Commands::Git {
+                command: GitCommands::PrepareCommitMsg { file, source, sha },
+            } => {
+                assert_eq!(file, PathBuf::from(".git/COMMIT_EDITMSG"));
+                assert_eq!(source, Some("message".to_string()));
+                assert!(sha.is_none());
+            }
+            _ => panic!("Expected Git PrepareCommitMsg command"),
+        }
+    }
+
+    #[test]
+    fn test_cli_parse_my() {
+        let cli = Cli::try_parse_from(["linearite", "my"]).unwrap();
+        match cli.command {
+            Commands::My(args) => {
+                assert!(args.relation == MyRelation::Assigned);
+                assert!(!args.all);
+            }
+            _ => panic!("Expected My command"),
+        }
+
+        let cli = Cli::try_parse_from(["linearite", "my", "subscribed", "-t", "ENG", "--all"]).unwrap();
+        match cli.command {
+            Commands::My(args) => {
+                assert!(args.relation == MyRelation::Subscribed);
+                assert_eq!(args.team, Some("ENG".to_string()));
+                assert!(args.all);
+            }
+            _ => panic!("Expected My command"),
+        }
+    }
+
+    #[test]
+    fn test_cli_parse_project_create() {
+        let cli = Cli::try_parse_from([
+            "linearite", "project", "create", "API v2", "-t", "ENG", "-t", "WEB", "--lead", "me",
+            "--target-date", "2026-12-01", "--content-file", "plan.md",
+        ])
+        .unwrap();
+        match cli.command {
+            Commands::Project {
+                command: ProjectCommands::Create(args),
+            } => {
+                assert_eq!(args.name, "API v2");
+                assert_eq!(args.teams, vec!["ENG", "WEB"]);
+                assert_eq!(args.fields.lead, Some("me".to_string()));
+                assert_eq!(args.fields.target_date, Some("2026-12-01".to_string()));
+                assert_eq!(args.fields.content_file, Some(PathBuf::from("plan.md")));
+            }
+            _ => panic!("Expected Project Create command"),
+        }
+
+        assert!(Cli::try_parse_from(["linearite", "project", "create", "API v2"]).is_err());
+    }
+
+    #[test]
+    fn test_cli_parse_project_update_and_archive() {
+        let cli = Cli::try_parse_from(["linearite", "project", "update", "API v2", "--state", "paused"]).unwrap();
+        match cli.command {
+            Commands::Project {
+                command: ProjectCommands::Update(args),
+            } => {
+                assert_eq!(args.project, "API v2");
+                assert_eq!(args.state, Some("paused".to_string()));
+                assert!(args.teams.is_empty());
+            }
+            _ => panic!("Expected Project Update command"),
+        }
+
+        let cli = Cli::try_parse_from(["linearite", "project", "archive", "API v2"]).unwrap();
+        match cli.command {
+            Commands::Project {
+                command: ProjectCommands::Archive { project },
+            } => assert_eq!(project, "API v2"),
+            _ => panic!("Expected Project Archive command"),
+        }
+    }
+
+    #[test]
+    fn test_cli_parse_update() {
+        let cli = Cli::try_parse_from(["linearite", "update", "ENG-1", "--milestone", "Beta"]).unwrap();
+        match cli.command {
+            Commands::Update(args) => {
+                assert_eq!(args.issue, Some("ENG-1".to_string()));
+                assert_eq!(args.milestone, "Beta");
+            }
+            _ => panic!("Expected Update command"),
+        }
+
+        assert!(Cli::try_parse_from(["linearite", "update", "ENG-1"]).is_err());
+        assert!(Cli::try_parse_from(["linearite", "update", "ENG-1", "--milestone", "Beta", "-s", "started"]).is_err());
+    }
+
+    #[test]
+    fn test_cli_parse_list() {
+        let cli = Cli::try_parse_from(["linearite", "list", "assignee:me", "state:started", "-n", "10"]).unwrap();
+        match cli.command {
+            Commands::List(args) => {
+                assert_eq!(args.query, vec!["assignee:me", "state:started"]);
+                assert_eq!(args.limit, 10);
+            }
+            _ => panic!("Expected List command"),
+        }
+
+        let cli = Cli::try_parse_from(["linearite", "list"]).unwrap();
+        assert!(matches!(cli.command, Commands::List(args) if args.query.is_empty() && args.limit == 50));
+    }
+
+    #[test]
+    fn test_cli_parse_saved_queries_and_views() {
+        let cli = Cli::try_parse_from(["linearite", "q", "triage", "assignee:me"]).unwrap();
+        match cli.command {
+            Commands::Q(args) => {
+                assert_eq!(args.name.as_deref(), Some("triage"));
+                assert_eq!(args.terms, vec!["assignee:me"]);
+                assert_eq!(args.limit, 50);
+            }
+            _ => panic!("Expected Q command"),
+        }
+        assert!(matches!(
+            Cli::try_parse_from(["linearite", "q"]).unwrap().command,
+            Commands::Q(args) if args.name.is_none()
+        ));
+
+        let cli = Cli::try_parse_from(["linearite", "view-list", "My Bugs", "-n", "5"]).unwrap();
+        match cli.command {
+            Commands::ViewList(args) => {
+                assert_eq!(args.view.as_deref(), Some("My Bugs"));
+                assert_eq!(args.limit, 5);
+            }
+            _ => panic!("Expected ViewList command"),
+        }
+    }
+
+    #[test]
+    fn test_cli_parse_bulk_update() {
+        let cli = Cli::try_parse_from([
+            "linearite",
+            "bulk",
+            "update",
+            "--filter",
+            "team:ENG state:backlog",
+            "--set",
+            "state=canceled",
+            "--add-label",
+            "archived",
+        ])
+        .unwrap();
+        match cli.command {
+            Commands::Bulk {
+                command: BulkCommands::Update(args),
+            } => {
+                assert_eq!(args.filter, "team:ENG state:backlog");
+                assert_eq!(args.set, vec!["state=canceled"]);
+                assert_eq!(args.add_labels, vec!["archived"]);
+                assert_eq!(args.concurrency, 4);
+                assert!(!args.yes);
+            }
+            _ => panic!("Expected Bulk Update command"),
+        }
+
+        assert!(Cli::try_parse_from(["linearite", "bulk", "update", "--filter", "team:ENG"]).is_err());
+    }
+
+    #[test]
+    fn test_cli_parse_archive_many() {
+        let cli = Cli::try_parse_from(["linearite", "archive", "ENG-1", "ENG-2", "-y"]).unwrap();
+        match cli.command {
+            Commands::Archive(args) => {
+                assert_eq!(args.issues, vec!["ENG-1", "ENG-2"]);
+                assert!(args.yes);
+            }
+            _ => panic!("Expected Archive command"),
+        }
+
+        let cli = Cli::try_parse_from(["linearite", "restore", "ENG-3"]).unwrap();
+        assert!(matches!(cli.command, Commands::Restore(args) if !args.yes));
+        assert!(Cli::try_parse_from(["linearite", "delete"]).is_err());
+    }
+
+    #[test]
+    fn test_cli_parse_attach() {
+        let cli = Cli::try_parse_from(["linearite", "attach", "ENG-1", "shot.png", "--to", "description"]).unwrap();
+        match cli.command {
+            Commands::Attach(args) => {
+                assert_eq!(args.issue, "ENG-1");
+                assert_eq!(args.file, Some(PathBuf::from("shot.png")));
+                assert_eq!(args.to, AttachTarget::Description);
+            }
+            _ => panic!("Expected Attach command"),
+        }
+
+        let cli = Cli::try_parse_from(["linearite", "attach", "ENG-1", "--url", "https://x.test", "--title", "PR"]).unwrap();
+        match cli.command {
+            Commands::Attach(args) => {
+                assert!(args.file.is_none());
+                assert_eq!(args.url, Some("https://x.test".to_string()));
+            }
+            _ => panic!("Expected Attach command"),
+        }
+
+        assert!(Cli::try_parse_from(["linearite", "attach", "ENG-1"]).is_err());
+        assert!(Cli::try_parse_from(["linearite", "attach", "ENG-1", "f.png", "--url", "https://x.test"]).is_err());
+    }
+
+    #[test]
+    fn test_cli_parse_milestone() {
+        let cli = Cli::try_parse_from([
+            "linearite", "milestone", "create", "Beta", "-p", "API v2", "--target-date", "2026-11-01",
+        ])
+        .unwrap();
+        match cli.command {
+            Commands::Milestone {
+                command: MilestoneCommands::Create(args),
+            } => {
+                assert_eq!(args.name, "Beta");
+                assert_eq!(args.project, "API v2");
+                assert_eq!(args.target_date, Some("2026-11-01".to_string()));
+            }
+            _ => panic!("Expected Milestone Create command"),
+        }
+
+        assert!(Cli::try_parse_from(["linearite", "milestone", "list"]).is_err());
+    }
+
+    #[test]
+    fn test_cli_parse_create_milestone_requires_project() {
+        assert!(Cli::try_parse_from(["linearite", "create", "Fix", "-t", "ENG", "--milestone", "Beta"]).is_err());
+        assert!(
+            Cli::try_parse_from(["linearite", "create", "Fix", "-t", "ENG", "-p", "API", "--milestone", "Beta"]).is_ok()
+        );
+    }
+
+    #[test]
+    fn test_cli_parse_project_post_update() {
+        let cli = Cli::try_parse_from([
+            "linearite", "project", "post-update", "API v2", "--health", "atRisk", "--body-file", "-",
+        ])
+        .unwrap();
+        match cli.command {
+            Commands::Project {
+                command: ProjectCommands::PostUpdate(args),
+            } => {
+                assert_eq!(args.health, ProjectHealth::AtRisk);
+                assert_eq!(args.body_file, Some(PathBuf::from("-")));
+            }
+            _ => panic!("Expected Project PostUpdate command"),
+        }
+
+        let cli = Cli::try_parse_from(["linearite", "project", "post-update", "P", "--health", "off-track", "-b", "x"]);
+        assert!(cli.is_ok());
+        assert!(Cli::try_parse_from(["linearite", "project", "post-update", "P", "--health", "onTrack"]).is_err());
+    }
+
+    #[test]
+    fn test_cli_parse_doc_create() {
+        let cli = Cli::try_parse_from([
+            "linearite", "doc", "create", "--project", "API v2", "--title", "Spec", "--content-file", "spec.md",
+        ])
+        .unwrap();
+        match cli.command {
+            Commands::Doc {
+                command: DocCommands::Create(args),
+            } => {
+                assert_eq!(args.project, "API v2");
+                assert_eq!(args.title, "Spec");
+                assert_eq!(args.content_file, Some(PathBuf::from("spec.md")));
+            }
+            _ => panic!("Expected Doc Create command"),
+        }
+
+        assert!(Cli::try_parse_from(["linearite", "doc", "create", "--title", "Spec"]).is_err());
+    }
+
+    #[test]
+    fn test_cli_parse_doc_export() {
+        let cli = Cli::try_parse_from(["linearite", "doc", "export", "abc123", "-o", "spec.md"]).unwrap();
+        match cli.command {
+            Commands::Doc {
+                command: DocCommands::Export { document, output },
+            } => {
+                assert_eq!(document, "abc123");
+                assert_eq!(output, Some(PathBuf::from("spec.md")));
+            }
+            _ => panic!("Expected Doc Export command"),
+        }
+    }
+
+    #[test]
+    fn test_cli_parse_initiative() {
+        let cli = Cli::try_parse_from(["linearite", "initiative", "add-project", "Onboarding", "API v2"]).unwrap();
+        match cli.command {
+            Commands::Initiative {
+                command: InitiativeCommands::AddProject { initiative, project },
+            } => {
+                assert_eq!(initiative, "Onboarding");
+                assert_eq!(project, "API v2");
+            }
+            _ => panic!("Expected Initiative AddProject command"),
+        }
+
+        let cli = Cli::try_parse_from(["linearite", "list-initiatives"]).unwrap();
+        assert!(matches!(cli.command, Commands::ListInitiatives));
+    }
+
+    #[test]
+    fn test_cli_parse_search() {
+        let cli = Cli::try_parse_from([
+            "linearite", "search", "payment timeout", "-t", "ENG", "-l", "bug", "-l", "api", "-n", "5",
+        ])
+        .unwrap();
+        match cli.command {
+            Commands::Search(args) => {
+                assert_eq!(args.query, "payment timeout");
+                assert!(args.kind == SearchKind::Issues);
+                assert_eq!(args.filters.team, Some("ENG".to_string()));
+                assert_eq!(args.filters.labels, vec!["bug", "api"]);
+                assert_eq!(args.limit, 5);
+                assert!(!args.filters.is_empty());
+            }
+            _ => panic!("Expected Search command"),
+        }
+    }
+
+    #[test]
+    fn test_cli_parse_search_documents() {
+        let cli = Cli::try_parse_from(["linearite", "search", "onboarding", "--kind", "documents"]).unwrap();
+        match cli.command {
+            Commands::Search(args) => {
+                assert!(args.kind == SearchKind::Documents);
+                assert!(args.filters.is_empty());
+            }
+            _ => panic!("Expected Search command"),
+        }
+    }
+
+    #[test]
+    fn test_cli_parse_todos_scan() {
+        let cli = Cli::try_parse_from([
+            "linearite", "todos", "scan", "src", "--create", "-t", "ENG", "-l", "tech-debt", "--rewrite",
+        ])
+        .unwrap();
+        match cli.command {
+            Commands::Todos {
+                command: TodosCommands::Scan(args),
+            } => {
+                assert_eq!(args.path, PathBuf::from("src"));
+                assert!(args.create);
+                assert!(args.rewrite);
+                assert_eq!(args.team_id, Some("ENG".to_string()));
+                assert_eq!(args.labels, vec!["tech-debt"]);
+            }
+            _ => panic!("Expected Todos Scan command"),
+        }
+
+        assert!(Cli::try_parse_from(["linearite", "todos", "scan", "--create"]).is_err());
+        assert!(Cli::try_parse_from(["linearite", "todos", "scan", "--rewrite"]).is_err());
+    }
+
+    #[test]
+    fn test_cli_parse_todos_check_default_path() {
+        let cli = Cli::try_parse_from(["linearite", "todos", "check"]).unwrap();
+        match cli.command {
+            Commands::Todos {
+                command: TodosCommands::Check { path },
+            } => assert_eq!(path, PathBuf::from(".")),
+            _ => panic!("Expected Todos Check command"),
+        }
+    }
+
+    #[test]
+    fn test_cli_parse_checkout() {
+        let cli = Cli::try_parse_from(["linearite", "checkout", "ENG-123", "--start", "--assign-me"])
+            .unwrap();
+        match cli.command {
+            Commands::Checkout(args) => {
+                assert_eq!(args.issue, "ENG-123");
+                assert!(args.start);
+                assert!(args.assign_me);
+            }
+            _ => panic!("Expected Checkout command"),
+        }
+    }
+
+    #[test]
+    fn test_cli_parse_create_checkout() {
+        let cli = Cli::try_parse_from(["linearite", "create", "Test Issue", "-t", "team-123", "--checkout"])
+            .unwrap();
+        match cli.command {
+            Commands::Create(args) => assert!(args.checkout),
+            _ => panic!("Expected Create command"),
+        }
+    }
+
+    #[test]
+    fn test_cli_parse_global_dry_run() {
+        let cli =
+            Cli::try_parse_from(["linearite", "create", "Test Issue", "-t", "team-123", "--dry-run"])
+                .unwrap();
+        assert!(cli.dry_run);
+
+        let cli = Cli::try_parse_from(["linearite", "--dry-run", "batch", "create"]).unwrap();
+        assert!(cli.dry_run);
+
+        let cli = Cli::try_parse_from(["linearite", "list-teams"]).unwrap();
+        assert!(!cli.dry_run);
+    }
+
+    #[test]
+    fn test_cli_parse_create_with_people() {
+        let cli = Cli::try_parse_from([
+            "linearite", "create", "Fix", "-t", "ENG", "-a", "me", "--subscriber", "alice", "--subscriber", "bob",
+        ])
+        .unwrap();
+        match cli.command {
+            Commands::Create(args) => {
+                assert_eq!(args.assignee, Some("me".to_string()));
+                assert_eq!(args.subscribers, vec!["alice", "bob"]);
+            }
+            _ => panic!("Expected Create command"),
+        }
+    }
+
+    #[test]
+    fn test_cli_parse_list_states() {
+        let cli = Cli::try_parse_from(["linearite", "list-states", "-t", "ENG"]).unwrap();
+        match cli.command {
+            Commands::ListStates { team } => assert_eq!(team, "ENG"),
+            _ => panic!("Expected ListStates command"),
+        }
+    }
+
+    #[test]
+    fn test_cli_parse_list_members() {
+        let cli = Cli::try_parse_from(["linearite", "list-members", "--team", "ENG"]).unwrap();
+        match cli.command {
+            Commands::ListMembers { team } => assert_eq!(team, "ENG"),
+            _ => panic!("Expected ListMembers command"),
+        }
+        assert!(Cli::try_parse_from(["linearite", "list-members"]).is_err());
+    }
+
     #[test]
     fn test_cli_parse_list_teams() {
-        let cli = Cli::try_parse_from(&["linearite", "list-teams"]).unwrap();
+        let cli = Cli::try_parse_from(["linearite", "list-teams"]).unwrap();
         match cli.command {
             Commands::ListTeams => {}
             _ => panic!("Expected ListTeams command"),
@@ -183,9 +1651,27 @@ mod tests {
 
     #[test]
     fn test_cli_parse_list_projects() {
-        let cli = Cli::try_parse_from(&["linearite", "list-projects"]).unwrap();
+        let cli = Cli::try_parse_from(["linearite", "list-projects"]).unwrap();
+        match cli.command {
+            Commands::ListProjects(args) => {
+                assert!(args.team.is_none() && args.state.is_none() && args.lead.is_none());
+            }
+            _ => panic!("Expected ListProjects command"),
+        }
+    }
+
+    #[test]
+    fn test_cli_parse_list_projects_filters() {
+        let cli = Cli::try_parse_from([
+            "linearite", "list-projects", "--team", "ENG", "--state", "started", "--lead", "me",
+        ])
+        .unwrap();
         match cli.command {
-            Commands::ListProjects => {}
+            Commands::ListProjects(args) => {
+                assert_eq!(args.team, Some("ENG".to_string()));
+                assert_eq!(args.state, Some("started".to_string()));
+                assert_eq!(args.lead, Some("me".to_string()));
+            }
             _ => panic!("Expected ListProjects command"),
         }
     }
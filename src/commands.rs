@@ -1,19 +1,79 @@
 use crate::api;
-use crate::types::{IssueCreateResponse, ProjectsResponse, TeamsResponse};
-use serde_json::json;
+use crate::batch;
+use crate::cli::{
+    AttachArgs, AttachTarget, BatchCreateArgs, BatchFormat, BulkUpdateArgs, CheckoutArgs, CloseArgs,
+    CommentArgs, CommitMsgArgs, CreateArgs, DocCreateArgs, DocUpdateArgs, IssuesActionArgs,
+    ListArgs, ListProjectsArgs, MilestoneCreateArgs, MyArgs, MyRelation, OnDuplicate, PrBodyArgs,
+    ProjectCreateArgs, ProjectFieldsArgs, ProjectPostUpdateArgs, ProjectUpdateArgs, SavedQueryArgs,
+    SearchArgs, SearchKind, TodosScanArgs, UpdateArgs, ViewArgs, ViewListArgs,
+};
+use crate::config;
+use crate::dedupe;
+use crate::filter;
+use crate::frontmatter;
+use crate::git;
+use crate::journal;
+use crate::message::{self, MagicWord};
+use crate::query;
+use crate::resolve;
+use crate::search;
+use crate::todos::{self, Todo};
+use crate::types::{
+    AttachmentCreateResponse, Comment, CommentCreateResponse, CustomView, CustomViewIssuesResponse,
+    CustomViewsResponse, DocumentCreateResponse, DocumentResponse, DocumentSearchResponse,
+    DocumentSummary, DocumentUpdateResponse, DocumentsResponse, FileUploadResponse,
+    InitiativeDetail, InitiativeDetailResponse, InitiativeToProjectCreateResponse, Issue,
+    IssueCreateResponse, IssueDetail, IssueResponse, IssueSearchResponse, IssueSpec, IssueStateNode,
+    IssueSummary, IssueUpdateResponse, IssuesResponse, Member, MembersResponse, Project,
+    ProjectArchiveResponse, ProjectCreateResponse, ProjectDetail, ProjectDetailResponse,
+    ProjectMilestoneCreateResponse, ProjectSearchResponse, ProjectStatusUpdate,
+    ProjectStatusUpdatesResponse, ProjectUpdateCreateResponse, ProjectUpdateResponse,
+    ProjectsResponse, Team, TeamMembersResponse, TeamsResponse, WhoamiResponse,
+};
+use crate::upload;
+use futures::stream::{self, StreamExt};
+use serde_json::{Map, Value, json};
+use std::collections::{BTreeMap, HashMap};
+use std::fs;
+use std::io::{self, IsTerminal, Read, Write};
+use std::path::{Path, PathBuf};
 
-pub async fn handle_create(
-    title: &str,
-    description: &Option<String>,
-    team_id: &str,
-    project_id: &Option<String>,
-) -> Result<(), Box<dyn std::error::Error>> {
-    let mutation = r#"
+const IDEMPOTENCY_MARKER: &str = "linearite-idempotency-key:";
+
+const ISSUE_CREATE_MUTATION: &str = r#"
         mutation IssueCreate($input: IssueCreateInput!) {
             issueCreate(input: $input) {
                 success
                 issue {
                     id
+                    identifier
+                    title
+                    url
+                    branchName
+                }
+            }
+        }
+    "#;
+
+const COMMENT_CREATE_MUTATION: &str = r#"
+        mutation CommentCreate($input: CommentCreateInput!) {
+            commentCreate(input: $input) {
+                success
+                comment {
+                    id
+                    url
+                }
+            }
+        }
+    "#;
+
+const ISSUE_UPDATE_MUTATION: &str = r#"
+        mutation IssueUpdate($id: String!, $input: IssueUpdateInput!) {
+            issueUpdate(id: $id, input: $input) {
+                success
+                issue {
+                    id
+                    identifier
                     title
                     url
                     branchName
@@ -22,67 +82,2313 @@ pub async fn handle_create(
         }
     "#;
 
-    let variables = json!({
-        "input": {
-            "teamId": team_id,
-            "projectId": project_id,
-            "title": title,
-            "description": description,
+pub async fn handle_create(args: &CreateArgs) -> Result<(), Box<dyn std::error::Error>> {
+    if let Some(path) = &args.from_file {
+        return handle_create_from_path(path, args).await;
+    }
+
+    if let Some(key) = &args.idempotency_key
+        && let Some(issue) = find_by_idempotency_key(key).await?
+    {
+        print_issue("issue already exists!", &issue);
+        if args.checkout {
+            checkout_issue_branch(&issue)?;
+        }
+        return Ok(());
+    }
+
+    let team_id = match &args.team_id {
+        Some(team) => Some(resolve::resolve_team(team).await?),
+        None => None,
+    };
+    if args.dedupe && check_duplicates(args, team_id.as_deref()).await? {
+        return Ok(());
+    }
+
+    let description = match &args.idempotency_key {
+        Some(key) => Some(with_idempotency_footer(args.description.as_deref(), key)),
+        None => args.description.clone(),
+    };
+
+    let project_id = match &args.project_id {
+        Some(project) => Some(resolve::resolve_project(project).await?),
+        None => None,
+    };
+
+    let mut variables = json!({
+        "input": {
+            "teamId": team_id,
+            "projectId": project_id,
+            "title": args.title,
+            "description": description,
+        }
+    });
+    if let Some(assignee) = &args.assignee {
+        variables["input"]["assigneeId"] = json!(resolve::resolve_user(assignee).await?);
+    }
+    if let (Some(milestone), Some(project_id)) = (&args.milestone, &project_id) {
+        variables["input"]["projectMilestoneId"] = json!(resolve::resolve_milestone(project_id, milestone).await?);
+    }
+    if !args.subscribers.is_empty() {
+        variables["input"]["subscriberIds"] = json!(resolve_users(&args.subscribers).await?);
+    }
+
+    match create_issue(variables).await? {
+        Some(issue) => {
+            if let Some(key) = &args.idempotency_key {
+                remember_idempotency_key(key, &issue);
+            }
+            print_issue("issue created!", &issue);
+            if args.checkout {
+                checkout_issue_branch(&issue)?;
+            }
+        }
+        None => eprintln!("[warning] issue creation reported success but no issue data returned"),
+    }
+
+    Ok(())
+}
+
+/// Appends the idempotency marker as the last line of the description, so the
+/// key is committed atomically with the issue itself.
+fn with_idempotency_footer(description: Option<&str>, key: &str) -> String {
+    let footer = format!("{} {}", IDEMPOTENCY_MARKER, key);
+    match description {
+        Some(description) if !description.trim().is_empty() => {
+            format!("{}\n\n{}", description.trim_end(), footer)
+        }
+        _ => footer,
+    }
+}
+
+fn has_idempotency_footer(description: &str, key: &str) -> bool {
+    let footer = format!("{} {}", IDEMPOTENCY_MARKER, key);
+    description.lines().any(|line| line.trim() == footer)
+}
+
+/// Checks the local journal first, then Linear itself, for an issue created with `key`.
+async fn find_by_idempotency_key(key: &str) -> Result<Option<Issue>, Box<dyn std::error::Error>> {
+    if let Some(issue) = journal::lookup(key) {
+        return Ok(Some(issue));
+    }
+
+    let variables = json!({
+        "filter": {
+            "description": { "contains": format!("{} {}", IDEMPOTENCY_MARKER, key) }
+        }
+    });
+    let data = api::query_linear::<IssuesResponse>(
+        "query IdempotentIssue($filter: IssueFilter) { issues(first: 10, filter: $filter, includeArchived: true) { nodes { id identifier title url branchName description } } }",
+        Some(variables),
+    )
+    .await?;
+
+    let issue = data
+        .issues
+        .nodes
+        .into_iter()
+        .find(|issue| {
+            issue
+                .description
+                .as_deref()
+                .is_some_and(|description| has_idempotency_footer(description, key))
+        })
+        .map(Issue::from);
+
+    if let Some(issue) = &issue {
+        remember_idempotency_key(key, issue);
+    }
+    Ok(issue)
+}
+
+fn remember_idempotency_key(key: &str, issue: &Issue) {
+    if let Err(e) = journal::record(key, issue.clone()) {
+        eprintln!("[warning] could not record idempotency key: {}", e);
+    }
+}
+
+/// Runs the `--dedupe` pre-flight. Returns `true` when the duplicate was
+/// handled by commenting on it and nothing else should be created.
+async fn check_duplicates(
+    args: &CreateArgs,
+    team_id: Option<&str>,
+) -> Result<bool, Box<dyn std::error::Error>> {
+    let (Some(title), Some(team_id)) = (&args.title, team_id) else {
+        return Ok(false);
+    };
+
+    let duplicates = dedupe::find_duplicates(team_id, title, args.dedupe_threshold).await?;
+    let Some(best) = duplicates.first() else {
+        return Ok(false);
+    };
+
+    for duplicate in &duplicates {
+        eprintln!(
+            "[duplicate] {}\t{:.2}\t{}\t{}",
+            duplicate.issue.identifier, duplicate.score, duplicate.issue.title, duplicate.issue.url
+        );
+    }
+    if args.force {
+        return Ok(false);
+    }
+
+    match args.on_duplicate {
+        OnDuplicate::Abort => Err(format!(
+            "[error] likely duplicate of {} (use --force to create anyway)",
+            best.issue.identifier
+        )
+        .into()),
+        OnDuplicate::Comment => {
+            let body = duplicate_comment_body(title, &args.description);
+            let comment = create_comment(&best.issue.id, &body).await?;
+            println!("commented on duplicate!");
+            println!("identifier: {}", best.issue.identifier);
+            println!("url: {}", comment.url.as_deref().unwrap_or(&best.issue.url));
+            Ok(true)
+        }
+    }
+}
+
+fn duplicate_comment_body(title: &str, description: &Option<String>) -> String {
+    match description {
+        Some(description) => format!("Reported again: **{}**\n\n{}", title, description),
+        None => format!("Reported again: **{}**", title),
+    }
+}
+
+async fn create_comment(issue_id: &str, body: &str) -> Result<Comment, Box<dyn std::error::Error>> {
+    let variables = json!({ "input": { "issueId": issue_id, "body": body } });
+    let data = api::mutate_linear::<CommentCreateResponse>(COMMENT_CREATE_MUTATION, Some(variables)).await?;
+
+    if !data.comment_create.success {
+        return Err("[error] comment creation failed".into());
+    }
+    data.comment_create
+        .comment
+        .ok_or_else(|| "comment creation reported success but no comment data returned".into())
+}
+
+async fn create_issue(variables: Value) -> Result<Option<Issue>, Box<dyn std::error::Error>> {
+    let data = api::mutate_linear::<IssueCreateResponse>(ISSUE_CREATE_MUTATION, Some(variables)).await?;
+
+    if !data.issue_create.success {
+        return Err("[error] issue creation failed".into());
+    }
+    Ok(data.issue_create.issue)
+}
+
+async fn update_issue(variables: Value) -> Result<Option<Issue>, Box<dyn std::error::Error>> {
+    let data = api::mutate_linear::<IssueUpdateResponse>(ISSUE_UPDATE_MUTATION, Some(variables)).await?;
+
+    if !data.issue_update.success {
+        return Err("[error] issue update failed".into());
+    }
+    Ok(data.issue_update.issue)
+}
+
+fn print_issue(heading: &str, issue: &Issue) {
+    println!("{}", heading);
+    println!("id: {}", issue.id);
+    if let Some(identifier) = &issue.identifier {
+        println!("identifier: {}", identifier);
+    }
+    println!("title: {}", issue.title);
+    println!("url: {}", issue.url);
+    if let Some(branch_name) = &issue.branch_name {
+        println!("branch name: {}", branch_name);
+    } else {
+        println!("branch name: not available");
+    }
+}
+
+pub async fn handle_checkout(args: &CheckoutArgs) -> Result<(), Box<dyn std::error::Error>> {
+    let issue = fetch_issue(&args.issue).await?;
+    let branch = issue
+        .branch_name
+        .clone()
+        .unwrap_or_else(|| git::default_branch_name(&issue.identifier, &issue.title));
+    switch_to_branch(&branch)?;
+
+    let mut input = Map::new();
+    if args.start {
+        input.insert("stateId".into(), json!(resolve::resolve_state(&issue.team.id, "started").await?));
+    }
+    if args.assign_me {
+        input.insert("assigneeId".into(), json!(resolve::resolve_user("me").await?));
+    }
+    if !input.is_empty() {
+        update_issue(json!({ "id": issue.id, "input": input })).await?;
+        println!("issue updated: {}", issue.identifier);
+    }
+
+    Ok(())
+}
+
+pub async fn handle_view(args: &ViewArgs) -> Result<(), Box<dyn std::error::Error>> {
+    let id = resolve::issue_or_current_branch(&args.issue)?;
+    let issue = fetch_issue(&id).await?;
+    let mut detail = format_issue_detail(&issue);
+    if let Some(dir) = &args.download_assets {
+        detail = download_assets(&detail, dir).await?;
+    }
+    print!("{}", detail);
+    Ok(())
+}
+
+/// Downloads every `uploads.linear.app` asset in `text` into `dir` and returns
+/// the text with links pointing at the local files. Failed downloads keep
+/// their remote URL.
+async fn download_assets(text: &str, dir: &Path) -> Result<String, Box<dyn std::error::Error>> {
+    let urls = upload::asset_urls(text);
+    if urls.is_empty() {
+        return Ok(text.to_string());
+    }
+    if api::is_dry_run() {
+        for url in &urls {
+            eprintln!("would download {} into {}", url, dir.display());
+        }
+        return Ok(text.to_string());
+    }
+    fs::create_dir_all(dir)?;
+
+    let mut downloads: Vec<_> = stream::iter(urls)
+        .map(|url| async move {
+            let result = api::download_asset(&url).await;
+            (url, result)
+        })
+        .buffered(4)
+        .collect()
+        .await;
+    // Longest first, so a URL that prefixes another isn't rewritten inside it.
+    downloads.sort_by_key(|(url, _)| std::cmp::Reverse(url.len()));
+
+    let mut text = text.to_string();
+    let mut used = Vec::new();
+    for (url, result) in downloads {
+        let (bytes, content_type) = match result {
+            Ok(download) => download,
+            Err(e) => {
+                eprintln!("[warning] {}", e);
+                continue;
+            }
+        };
+
+        let name = upload::asset_file_name(&url, content_type.as_deref());
+        let mut unique = name.clone();
+        let mut n = 1;
+        while used.contains(&unique) {
+            n += 1;
+            unique = format!("{}-{}", n, name);
+        }
+        let path = dir.join(&unique);
+        fs::write(&path, bytes)?;
+        used.push(unique);
+        text = text.replace(&url, &path.display().to_string());
+    }
+    Ok(text)
+}
+
+pub async fn handle_comment(args: &CommentArgs) -> Result<(), Box<dyn std::error::Error>> {
+    let id = resolve::issue_or_current_branch(&args.issue)?;
+    let body = match (&args.body, &args.body_file) {
+        (Some(body), _) => body.clone(),
+        (None, Some(path)) => read_input(path)?,
+        (None, None) => return Err("comment body is required (--body or --body-file)".into()),
+    };
+
+    let issue_id = resolve::resolve_issue(&id).await?;
+    let comment = create_comment(&issue_id, &body).await?;
+    println!("comment added!");
+    println!("issue: {}", id);
+    if let Some(url) = &comment.url {
+        println!("url: {}", url);
+    }
+    Ok(())
+}
+
+pub async fn handle_close(args: &CloseArgs) -> Result<(), Box<dyn std::error::Error>> {
+    let id = resolve::issue_or_current_branch(&args.issue)?;
+    let issue = fetch_issue(&id).await?;
+    let state_type = if args.canceled { "canceled" } else { "completed" };
+    let state_id = resolve::resolve_state(&issue.team.id, state_type).await?;
+
+    update_issue(json!({ "id": issue.id, "input": { "stateId": state_id } })).await?;
+    println!("issue closed: {}", issue.identifier);
+    Ok(())
+}
+
+pub async fn handle_update(args: &UpdateArgs) -> Result<(), Box<dyn std::error::Error>> {
+    let id = resolve::issue_or_current_branch(&args.issue)?;
+    let issue = fetch_issue(&id).await?;
+    let milestone_id = resolve::resolve_milestone(milestone_project(&issue)?, &args.milestone).await?;
+
+    let input = json!({ "projectMilestoneId": milestone_id });
+    match update_issue(json!({ "id": issue.id, "input": input })).await? {
+        Some(updated) => print_issue("issue updated!", &updated),
+        None => println!("issue updated: {}", issue.identifier),
+    }
+    Ok(())
+}
+
+/// Milestones belong to a project, so the issue has to be in one.
+fn milestone_project(issue: &IssueDetail) -> Result<&str, String> {
+    issue
+        .project
+        .as_ref()
+        .map(|project| project.id.as_str())
+        .ok_or_else(|| format!("{} is not in a project, so it can't have a milestone", issue.identifier))
+}
+
+#[derive(Clone, Copy)]
+pub enum IssuesAction {
+    Archive,
+    Unarchive,
+    Delete,
+    Restore,
+}
+
+impl IssuesAction {
+    /// Trashed issues come back through `issueUnarchive`, like archived ones.
+    fn mutation(self) -> &'static str {
+        match self {
+            IssuesAction::Archive => "issueArchive",
+            IssuesAction::Unarchive | IssuesAction::Restore => "issueUnarchive",
+            IssuesAction::Delete => "issueDelete",
+        }
+    }
+
+    fn verb(self) -> &'static str {
+        match self {
+            IssuesAction::Archive => "archive",
+            IssuesAction::Unarchive => "unarchive",
+            IssuesAction::Delete => "delete",
+            IssuesAction::Restore => "restore",
+        }
+    }
+
+    fn past_tense(self) -> &'static str {
+        match self {
+            IssuesAction::Archive => "archived",
+            IssuesAction::Unarchive => "unarchived",
+            IssuesAction::Delete => "deleted",
+            IssuesAction::Restore => "restored",
+        }
+    }
+}
+
+pub async fn handle_issues_action(
+    action: IssuesAction,
+    args: &IssuesActionArgs,
+) -> Result<(), Box<dyn std::error::Error>> {
+    if !args.yes && !api::is_dry_run() && !confirm(&confirmation_prompt(action, &args.issues))? {
+        return Err("aborted".into());
+    }
+
+    let mutation = format!(
+        "mutation {}($id: String!) {{ {}(id: $id) {{ success }} }}",
+        action.mutation().replacen('i', "I", 1),
+        action.mutation()
+    );
+    let results: Vec<_> = stream::iter(&args.issues)
+        .map(|issue| {
+            let mutation = &mutation;
+            async move {
+                let result = async {
+                    let id = resolve::resolve_issue(issue).await?;
+                    let data =
+                        api::mutate_linear::<Value>(mutation, Some(json!({ "id": id }))).await?;
+                    if data[action.mutation()]["success"] != json!(true) {
+                        return Err(format!("could not {} {}", action.verb(), issue).into());
+                    }
+                    Ok::<_, Box<dyn std::error::Error>>(())
+                }
+                .await;
+                (issue, result)
+            }
+        })
+        .buffered(4)
+        .collect()
+        .await;
+
+    let mut failures = 0;
+    for (issue, result) in results {
+        match result {
+            Ok(()) => println!("{}\t{}", action.past_tense(), issue),
+            Err(e) if e.is::<api::DryRun>() => {}
+            Err(e) => {
+                failures += 1;
+                eprintln!("[error] {}: {}", issue, e);
+            }
+        }
+    }
+
+    if failures > 0 {
+        return Err(format!(
+            "{} of {} issues could not be {}",
+            failures,
+            args.issues.len(),
+            action.past_tense()
+        )
+        .into());
+    }
+    Ok(())
+}
+
+const BULK_FIELDS: &[&str] = &["state", "assignee", "priority", "project"];
+
+pub async fn handle_bulk_update(args: &BulkUpdateArgs) -> Result<(), Box<dyn std::error::Error>> {
+    let fields = bulk_fields(&args.set)?;
+    let filter = query::compile(&args.filter).await?;
+    if filter.as_object().is_none_or(Map::is_empty) {
+        return Err("--filter must narrow down the issues, e.g. 'team:ENG state:backlog'".into());
+    }
+    let issues = fetch_issues(&IssueSource::Filter(filter), None).await?;
+    if issues.is_empty() {
+        println!("no issues match: {}", args.filter);
+        return Ok(());
+    }
+
+    // States and team labels differ between teams, so the input is resolved
+    // once per team before anything is changed.
+    let mut inputs: HashMap<&str, Value> = HashMap::new();
+    for issue in &issues {
+        let team_id = issue
+            .team
+            .as_ref()
+            .map(|team| team.id.as_str())
+            .ok_or_else(|| format!("{} has no team", issue.identifier))?;
+        if !inputs.contains_key(team_id) {
+            inputs.insert(team_id, bulk_input(&fields, &args.add_labels, team_id).await?);
+        }
+    }
+
+    println!("{} issues match: {}", issues.len(), args.filter);
+    for (state, group) in group_by_state(&issues) {
+        println!("  {} ({})", state, group.len());
+        for issue in group {
+            println!("    {}\t{}", issue.identifier, issue.title);
+        }
+    }
+    println!("changes: {}", describe_changes(&fields, &args.add_labels));
+
+    let noun = if issues.len() == 1 { "issue" } else { "issues" };
+    let prompt = format!("Update {} {}? [y/N] ", issues.len(), noun);
+    if !args.yes && !api::is_dry_run() && !confirm(&prompt)? {
+        return Err("aborted".into());
+    }
+
+    let results: Vec<_> = stream::iter(&issues)
+        .map(|issue| {
+            let input = issue.team.as_ref().and_then(|team| inputs.get(team.id.as_str()));
+            async move {
+                let result = update_issue(json!({ "id": issue.id, "input": input })).await;
+                (issue, result)
+            }
+        })
+        .buffered(args.concurrency.max(1))
+        .collect()
+        .await;
+
+    let mut updated = 0;
+    let mut failures = 0;
+    for (issue, result) in results {
+        match result {
+            Ok(_) => {
+                updated += 1;
+                println!("updated\t{}", issue.identifier);
+            }
+            Err(e) if e.is::<api::DryRun>() => {}
+            Err(e) => {
+                failures += 1;
+                eprintln!("[error] {}: {}", issue.identifier, e);
+            }
+        }
+    }
+
+    if api::is_dry_run() {
+        return Ok(());
+    }
+    println!("updated {} of {} issues", updated, issues.len());
+    if failures > 0 {
+        return Err(format!("{} issues could not be updated", failures).into());
+    }
+    Ok(())
+}
+
+/// Splits `--set FIELD=VALUE` arguments, rejecting unknown fields.
+fn bulk_fields(set: &[String]) -> Result<Vec<(&'static str, &str)>, String> {
+    set.iter()
+        .map(|assignment| {
+            let (field, value) = assignment
+                .split_once('=')
+                .ok_or_else(|| format!("invalid --set: {} (expected FIELD=VALUE)", assignment))?;
+            let field = BULK_FIELDS
+                .iter()
+                .find(|known| known.eq_ignore_ascii_case(field.trim()))
+                .ok_or_else(|| {
+                    format!("unknown field in --set: {} (expected {})", field, BULK_FIELDS.join(", "))
+                })?;
+            Ok((*field, value.trim()))
+        })
+        .collect()
+}
+
+/// `none` clears the assignee or project.
+async fn bulk_input(
+    fields: &[(&str, &str)],
+    add_labels: &[String],
+    team_id: &str,
+) -> Result<Value, Box<dyn std::error::Error>> {
+    let mut input = Map::new();
+    for (field, value) in fields {
+        let clear = value.eq_ignore_ascii_case("none");
+        match *field {
+            "state" => {
+                input.insert("stateId".into(), json!(resolve::resolve_state(team_id, value).await?));
+            }
+            "assignee" if clear => {
+                input.insert("assigneeId".into(), Value::Null);
+            }
+            "assignee" => {
+                input.insert("assigneeId".into(), json!(resolve::resolve_user(value).await?));
+            }
+            "priority" => {
+                input.insert("priority".into(), json!(resolve::parse_priority(value)?));
+            }
+            "project" if clear => {
+                input.insert("projectId".into(), Value::Null);
+            }
+            _ => {
+                input.insert("projectId".into(), json!(resolve::resolve_project(value).await?));
+            }
+        }
+    }
+    if !add_labels.is_empty() {
+        input.insert("addedLabelIds".into(), json!(resolve::resolve_labels(team_id, add_labels).await?));
+    }
+    Ok(Value::Object(input))
+}
+
+fn describe_changes(fields: &[(&str, &str)], add_labels: &[String]) -> String {
+    fields
+        .iter()
+        .map(|(field, value)| format!("{}={}", field, value))
+        .chain(add_labels.iter().map(|label| format!("+label {}", label)))
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+/// Where listed issues come from.
+enum IssueSource {
+    Filter(Value),
+    /// A custom view, by id. Its own connection applies the view's filters and team.
+    View(String),
+}
+
+const ISSUE_ROW_FIELDS: &str = "nodes { id identifier title url team { id key } state { name type } \
+     assignee { id name } priorityLabel } pageInfo { hasNextPage endCursor }";
+
+/// Issues from the source, following pagination up to `limit` (or to the end).
+/// Filtered issues come most recently updated first.
+async fn fetch_issues(
+    source: &IssueSource,
+    limit: Option<usize>,
+) -> Result<Vec<IssueSummary>, Box<dyn std::error::Error>> {
+    let mut issues = Vec::new();
+    let mut after: Option<String> = None;
+    loop {
+        let first = limit.map_or(100, |limit| (limit - issues.len()).min(100));
+        let page = match source {
+            IssueSource::Filter(filter) => {
+                let query = format!(
+                    "query FilteredIssues($filter: IssueFilter, $first: Int, $after: String) {{ \
+                     issues(first: $first, after: $after, filter: $filter, orderBy: updatedAt) {{ {} }} }}",
+                    ISSUE_ROW_FIELDS
+                );
+                let variables = json!({ "filter": filter, "first": first, "after": after });
+                api::query_linear::<IssuesResponse>(&query, Some(variables)).await?.issues
+            }
+            IssueSource::View(id) => {
+                let query = format!(
+                    "query CustomViewIssues($id: String!, $first: Int, $after: String) {{ \
+                     customView(id: $id) {{ issues(first: $first, after: $after) {{ {} }} }} }}",
+                    ISSUE_ROW_FIELDS
+                );
+                let variables = json!({ "id": id, "first": first, "after": after });
+                api::query_linear::<CustomViewIssuesResponse>(&query, Some(variables))
+                    .await?
+                    .custom_view
+                    .issues
+            }
+        };
+        issues.extend(page.nodes);
+        let done = limit.is_some_and(|limit| issues.len() >= limit);
+        match page.page_info {
+            Some(page_info) if !done && page_info.has_next_page && page_info.end_cursor.is_some() => {
+                after = page_info.end_cursor;
+            }
+            _ => return Ok(issues),
+        }
+    }
+}
+
+pub async fn handle_list(args: &ListArgs) -> Result<(), Box<dyn std::error::Error>> {
+    let filter = query::compile(&args.query.join(" ")).await?;
+    print_issues(&IssueSource::Filter(filter), args.limit).await
+}
+
+async fn print_issues(source: &IssueSource, limit: usize) -> Result<(), Box<dyn std::error::Error>> {
+    if limit == 0 {
+        return Ok(());
+    }
+    for issue in fetch_issues(source, Some(limit)).await? {
+        println!("{}", format_issue_row(&issue));
+    }
+    Ok(())
+}
+
+pub async fn handle_saved_query(args: &SavedQueryArgs) -> Result<(), Box<dyn std::error::Error>> {
+    let config = config::load()?;
+    let Some(name) = &args.name else {
+        for (name, query) in &config.queries {
+            println!("{}\t{}", name, query);
+        }
+        return Ok(());
+    };
+
+    let saved = config.queries.get(name).ok_or_else(|| {
+        let path = config::config_path().map_or("the config file".to_string(), |path| path.display().to_string());
+        format!("saved query not found: {} (add it under [queries] in {})", name, path)
+    })?;
+    let query = std::iter::once(saved.as_str())
+        .chain(args.terms.iter().map(String::as_str))
+        .collect::<Vec<_>>()
+        .join(" ");
+    print_issues(&IssueSource::Filter(query::compile(&query).await?), args.limit).await
+}
+
+pub async fn handle_view_list(args: &ViewListArgs) -> Result<(), Box<dyn std::error::Error>> {
+    let data = api::query_linear::<CustomViewsResponse>(
+        "query CustomViews { customViews(first: 250) { nodes { id name shared } } }",
+        None,
+    )
+    .await?;
+    let views = data.custom_views.nodes;
+
+    let Some(value) = &args.view else {
+        for view in &views {
+            let visibility = if view.shared == Some(true) { "shared" } else { "personal" };
+            println!("{}\t{}\t{}", view.name, visibility, view.id);
+        }
+        return Ok(());
+    };
+
+    let view = find_view(&views, value).ok_or_else(|| format!("custom view not found: {}", value))?;
+    print_issues(&IssueSource::View(view.id.clone()), args.limit).await
+}
+
+fn find_view<'a>(views: &'a [CustomView], value: &str) -> Option<&'a CustomView> {
+    views
+        .iter()
+        .find(|view| view.id == value)
+        .or_else(|| views.iter().find(|view| view.name.eq_ignore_ascii_case(value.trim())))
+}
+
+fn format_issue_row(issue: &IssueSummary) -> String {
+    format!(
+        "{}\t{}\t{}\t{}\t{}",
+        issue.identifier,
+        issue.title,
+        issue.state.as_ref().map_or("-", |state| state.name.as_str()),
+        issue.assignee.as_ref().map_or("-", |assignee| assignee.name.as_str()),
+        issue.priority_label.as_deref().unwrap_or("-"),
+    )
+}
+
+fn confirmation_prompt(action: IssuesAction, issues: &[String]) -> String {
+    let mut verb = action.verb().to_string();
+    verb[..1].make_ascii_uppercase();
+    let noun = if issues.len() == 1 { "issue" } else { "issues" };
+    format!("{} {} {} ({})? [y/N] ", verb, issues.len(), noun, issues.join(", "))
+}
+
+/// Asks on the terminal; without one, pass `--yes` instead.
+fn confirm(prompt: &str) -> Result<bool, Box<dyn std::error::Error>> {
+    if !io::stdin().is_terminal() {
+        return Err("refusing to continue without confirmation: pass --yes".into());
+    }
+    eprint!("{}", prompt);
+    io::stderr().flush()?;
+    let mut answer = String::new();
+    io::stdin().read_line(&mut answer)?;
+    Ok(matches!(answer.trim().to_ascii_lowercase().as_str(), "y" | "yes"))
+}
+
+pub async fn handle_attach(args: &AttachArgs) -> Result<(), Box<dyn std::error::Error>> {
+    let issue = fetch_issue(&args.issue).await?;
+
+    if let Some(url) = &args.url {
+        let data = api::mutate_linear::<AttachmentCreateResponse>(
+            "mutation AttachmentCreate($input: AttachmentCreateInput!) { \
+             attachmentCreate(input: $input) { success attachment { id title url } } }",
+            Some(json!({
+                "input": {
+                    "issueId": issue.id,
+                    "url": url,
+                    "title": args.title.as_deref().unwrap_or(url),
+                }
+            })),
+        )
+        .await?;
+
+        if !data.attachment_create.success {
+            return Err("[error] attachment creation failed".into());
+        }
+        println!("link attached!");
+        println!("issue: {}", issue.identifier);
+        if let Some(attachment) = data.attachment_create.attachment {
+            println!("id: {}", attachment.id);
+            println!("title: {}", attachment.title);
+            println!("url: {}", attachment.url);
+        }
+        return Ok(());
+    }
+
+    let path = args.file.as_deref().ok_or("a file or --url is required")?;
+    let bytes = fs::read(path).map_err(|e| format!("{}: {}", path.display(), e))?;
+    let filename = path
+        .file_name()
+        .and_then(|name| name.to_str())
+        .ok_or_else(|| format!("invalid file name: {}", path.display()))?;
+    let content_type = args
+        .content_type
+        .as_deref()
+        .unwrap_or_else(|| upload::content_type(path));
+
+    let data = api::mutate_linear::<FileUploadResponse>(
+        "mutation FileUpload($contentType: String!, $filename: String!, $size: Int!) { \
+         fileUpload(contentType: $contentType, filename: $filename, size: $size) { \
+         success uploadFile { uploadUrl assetUrl headers { key value } } } }",
+        Some(json!({ "contentType": content_type, "filename": filename, "size": bytes.len() })),
+    )
+    .await?;
+    let upload_file = data
+        .file_upload
+        .upload_file
+        .filter(|_| data.file_upload.success)
+        .ok_or("[error] file upload could not be started")?;
+
+    let headers: Vec<(String, String)> = upload_file
+        .headers
+        .into_iter()
+        .map(|header| (header.key, header.value))
+        .collect();
+    api::upload_file(&upload_file.upload_url, &headers, content_type, bytes).await?;
+
+    let name = args.title.as_deref().unwrap_or(filename);
+    let markdown = upload::asset_markdown(name, content_type, &upload_file.asset_url);
+    match args.to {
+        AttachTarget::Comment => {
+            create_comment(&issue.id, &markdown).await?;
+        }
+        AttachTarget::Description => {
+            let description = match issue.description.as_deref().map(str::trim_end) {
+                Some(description) if !description.is_empty() => format!("{}\n\n{}", description, markdown),
+                _ => markdown,
+            };
+            update_issue(json!({ "id": issue.id, "input": { "description": description } })).await?;
+        }
+    }
+
+    println!("file attached!");
+    println!("issue: {}", issue.identifier);
+    println!("asset url: {}", upload_file.asset_url);
+    Ok(())
+}
+
+pub async fn handle_git_commit_msg(args: &CommitMsgArgs) -> Result<(), Box<dyn std::error::Error>> {
+    let id = resolve::issue_or_current_branch(&args.issue)?;
+    let issue = fetch_issue(&id).await?;
+    print!("{}", message::commit_message(&issue, args.commit_type.as_deref(), args.magic_word));
+    Ok(())
+}
+
+pub async fn handle_git_pr_body(args: &PrBodyArgs) -> Result<(), Box<dyn std::error::Error>> {
+    let id = resolve::issue_or_current_branch(&args.issue)?;
+    let issue = fetch_issue(&id).await?;
+    print!("{}", message::pr_body(&issue, args.magic_word, !args.no_description));
+    Ok(())
+}
+
+const PREPARE_COMMIT_MSG_HOOK: &str = r#"#!/bin/sh
+# Installed by linearite: adds the Linear issue from the branch name to commit messages.
+command -v linearite >/dev/null 2>&1 || exit 0
+linearite git prepare-commit-msg "$@" || true
+"#;
+
+pub fn handle_git_install_hook(force: bool) -> Result<(), Box<dyn std::error::Error>> {
+    let path = git::hook_path("prepare-commit-msg")?;
+    if let Ok(existing) = fs::read_to_string(&path)
+        && !existing.contains("linearite")
+        && !force
+    {
+        return Err(format!("{} already exists (use --force to replace it)", path.display()).into());
+    }
+
+    if api::is_dry_run() {
+        println!("would write {}:\n{}", path.display(), PREPARE_COMMIT_MSG_HOOK);
+        return Ok(());
+    }
+
+    if let Some(dir) = path.parent() {
+        fs::create_dir_all(dir)?;
+    }
+    fs::write(&path, PREPARE_COMMIT_MSG_HOOK)?;
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::PermissionsExt;
+        fs::set_permissions(&path, fs::Permissions::from_mode(0o755))?;
+    }
+
+    println!("hook installed: {}", path.display());
+    Ok(())
+}
+
+/// Body of the `prepare-commit-msg` hook. Fresh commits get the full
+/// conventional message; messages given with `-m` or a template only get the
+/// magic-word trailer. Anything unexpected leaves the message untouched.
+pub async fn handle_git_prepare_commit_msg(
+    file: &Path,
+    source: Option<&str>,
+) -> Result<(), Box<dyn std::error::Error>> {
+    if matches!(source, Some("merge" | "squash" | "commit")) {
+        return Ok(());
+    }
+    // A hook must never break a commit, so anything unconfirmed is left alone:
+    // the trailer is only written for an issue that was actually fetched.
+    let Ok(branch) = git::current_branch() else {
+        return Ok(());
+    };
+    let Ok(Some(identifier)) = resolve::branch_issue(&branch).await else {
+        return Ok(());
+    };
+
+    let current = fs::read_to_string(file)?;
+    if current.contains(&identifier) {
+        return Ok(());
+    }
+    let Ok(issue) = fetch_issue(&identifier).await else {
+        return Ok(());
+    };
+
+    let updated = match source {
+        None => message::prepend_message(&current, &message::commit_message(&issue, None, MagicWord::Fixes)),
+        Some(_) => message::append_trailer(&current, &message::trailer(&issue.identifier, MagicWord::Fixes)),
+    };
+
+    fs::write(file, updated)?;
+    Ok(())
+}
+
+pub async fn handle_whoami() -> Result<(), Box<dyn std::error::Error>> {
+    let data = api::query_linear::<WhoamiResponse>(
+        "query Whoami { viewer { id name displayName email organization { name urlKey } teams { nodes { id name key } } } }",
+        None,
+    )
+    .await?;
+
+    let viewer = data.viewer;
+    println!("id: {}", viewer.id);
+    println!("name: {}", viewer.name);
+    if let Some(display_name) = &viewer.display_name {
+        println!("display name: {}", display_name);
+    }
+    if let Some(email) = &viewer.email {
+        println!("email: {}", email);
+    }
+    println!("organization: {} ({})", viewer.organization.name, viewer.organization.url_key);
+    println!("teams:");
+    for team in &viewer.teams.nodes {
+        println!("  {}\t{}\t{}", team.key.as_deref().unwrap_or("-"), team.name, team.id);
+    }
+    Ok(())
+}
+
+pub async fn handle_my(args: &MyArgs) -> Result<(), Box<dyn std::error::Error>> {
+    let me = json!({ "isMe": { "eq": true } });
+    let mut filter = match args.relation {
+        MyRelation::Assigned => json!({ "assignee": me }),
+        MyRelation::Created => json!({ "creator": me }),
+        MyRelation::Subscribed => json!({ "subscribers": { "some": me } }),
+    };
+    if let Some(team) = &args.team {
+        filter["team"] = json!({ "id": { "eq": resolve::resolve_team(team).await? } });
+    }
+    if !args.all {
+        filter["state"] = json!({ "type": { "nin": ["completed", "canceled"] } });
+    }
+
+    let data = api::query_linear::<IssuesResponse>(
+        "query MyIssues($filter: IssueFilter) { issues(first: 250, filter: $filter) { nodes { id identifier title url state { name type } } } }",
+        Some(json!({ "filter": filter })),
+    )
+    .await?;
+
+    for (state, issues) in group_by_state(&data.issues.nodes) {
+        println!("{} ({})", state, issues.len());
+        for issue in issues {
+            println!("  {}\t{}", issue.identifier, issue.title);
+        }
+    }
+    Ok(())
+}
+
+const STATE_TYPE_ORDER: &[&str] = &["started", "unstarted", "backlog", "triage", "completed", "canceled"];
+
+/// Groups issues under their state name, most active state types first.
+fn group_by_state(issues: &[IssueSummary]) -> Vec<(&str, Vec<&IssueSummary>)> {
+    let rank = |issue: &IssueSummary| {
+        let state_type = issue.state.as_ref().map_or("", |state| state.state_type.as_str());
+        STATE_TYPE_ORDER
+            .iter()
+            .position(|t| *t == state_type)
+            .unwrap_or(STATE_TYPE_ORDER.len())
+    };
+
+    let mut sorted: Vec<&IssueSummary> = issues.iter().collect();
+    sorted.sort_by_key(|issue| rank(issue));
+
+    let mut groups: Vec<(&str, Vec<&IssueSummary>)> = Vec::new();
+    for issue in sorted {
+        let state = issue.state.as_ref().map_or("No state", |state| state.name.as_str());
+        match groups.iter_mut().find(|(name, _)| *name == state) {
+            Some((_, group)) => group.push(issue),
+            None => groups.push((state, vec![issue])),
+        }
+    }
+    groups
+}
+
+const PROJECT_FIELDS: &str = "id name state lead { id name } targetDate progress teams { nodes { id key name } } url";
+
+pub async fn handle_project_create(args: &ProjectCreateArgs) -> Result<(), Box<dyn std::error::Error>> {
+    let mut input = project_input(&args.fields).await?;
+    input.insert("name".into(), json!(args.name));
+    input.insert("teamIds".into(), json!(resolve_teams(&args.teams).await?));
+
+    let data = api::mutate_linear::<ProjectCreateResponse>(
+        &format!(
+            "mutation ProjectCreate($input: ProjectCreateInput!) {{ projectCreate(input: $input) {{ success project {{ {} }} }} }}",
+            PROJECT_FIELDS
+        ),
+        Some(json!({ "input": input })),
+    )
+    .await?;
+
+    if !data.project_create.success {
+        return Err("[error] project creation failed".into());
+    }
+    let project = data
+        .project_create
+        .project
+        .ok_or("project creation reported success but no project data returned")?;
+    print_project("project created!", &project);
+    Ok(())
+}
+
+pub async fn handle_project_update(args: &ProjectUpdateArgs) -> Result<(), Box<dyn std::error::Error>> {
+    let project_id = resolve::resolve_project(&args.project).await?;
+    let mut input = project_input(&args.fields).await?;
+    if let Some(name) = &args.name {
+        input.insert("name".into(), json!(name));
+    }
+    if !args.teams.is_empty() {
+        input.insert("teamIds".into(), json!(resolve_teams(&args.teams).await?));
+    }
+    if let Some(state) = &args.state {
+        input.insert("state".into(), json!(state.to_ascii_lowercase()));
+    }
+    if input.is_empty() {
+        return Err("nothing to update: pass at least one field".into());
+    }
+
+    let data = api::mutate_linear::<ProjectUpdateResponse>(
+        &format!(
+            "mutation ProjectUpdate($id: String!, $input: ProjectUpdateInput!) {{ projectUpdate(id: $id, input: $input) {{ success project {{ {} }} }} }}",
+            PROJECT_FIELDS
+        ),
+        Some(json!({ "id": project_id, "input": input })),
+    )
+    .await?;
+
+    if !data.project_update.success {
+        return Err("[error] project update failed".into());
+    }
+    let project = data
+        .project_update
+        .project
+        .ok_or("project update reported success but no project data returned")?;
+    print_project("project updated!", &project);
+    Ok(())
+}
+
+pub async fn handle_project_archive(project: &str) -> Result<(), Box<dyn std::error::Error>> {
+    let project_id = resolve::resolve_project(project).await?;
+    let data = api::mutate_linear::<ProjectArchiveResponse>(
+        "mutation ProjectArchive($id: String!) { projectArchive(id: $id) { success } }",
+        Some(json!({ "id": project_id })),
+    )
+    .await?;
+
+    if !data.project_archive.success {
+        return Err("[error] project archive failed".into());
+    }
+    println!("project archived: {}", project);
+    Ok(())
+}
+
+pub async fn handle_project_view(project: &str) -> Result<(), Box<dyn std::error::Error>> {
+    let project_id = resolve::resolve_project(project).await?;
+    let data = api::query_linear::<ProjectDetailResponse>(
+        "query Project($id: String!) { project(id: $id) { \
+         id name description content state progress startDate targetDate url lead { id name } \
+         teams { nodes { id key name } } members(first: 250) { nodes { id name } } \
+         projectMilestones { nodes { id name targetDate } } \
+         issues(first: 250) { nodes { state { name type } } } } }",
+        Some(json!({ "id": project_id })),
+    )
+    .await?;
+
+    print!("{}", format_project_detail(&data.project));
+    Ok(())
+}
+
+pub async fn handle_project_post_update(args: &ProjectPostUpdateArgs) -> Result<(), Box<dyn std::error::Error>> {
+    let project_id = resolve::resolve_project(&args.project).await?;
+    let body = match &args.body_file {
+        Some(path) => read_input(path)?,
+        None => args.body.clone().unwrap_or_default(),
+    };
+    if body.trim().is_empty() {
+        return Err("status update body is empty".into());
+    }
+
+    let data = api::mutate_linear::<ProjectUpdateCreateResponse>(
+        "mutation ProjectUpdateCreate($input: ProjectUpdateCreateInput!) { \
+         projectUpdateCreate(input: $input) { success projectUpdate { id health createdAt url } } }",
+        Some(json!({
+            "input": {
+                "projectId": project_id,
+                "health": args.health.as_str(),
+                "body": body,
+            }
+        })),
+    )
+    .await?;
+
+    if !data.project_update_create.success {
+        return Err("[error] project update creation failed".into());
+    }
+    println!("project update posted!");
+    if let Some(update) = data.project_update_create.project_update {
+        println!("id: {}", update.id);
+        if let Some(url) = &update.url {
+            println!("url: {}", url);
+        }
+    }
+    Ok(())
+}
+
+pub async fn handle_project_updates(project: &str, limit: u32) -> Result<(), Box<dyn std::error::Error>> {
+    let project_id = resolve::resolve_project(project).await?;
+    let data = api::query_linear::<ProjectStatusUpdatesResponse>(
+        "query ProjectUpdates($id: String!, $first: Int) { project(id: $id) { \
+         projectUpdates(first: $first) { nodes { id body health createdAt url user { id name } } } } }",
+        Some(json!({ "id": project_id, "first": limit })),
+    )
+    .await?;
+
+    for update in &data.project.project_updates.nodes {
+        print!("{}", format_project_status_update(update));
+    }
+    Ok(())
+}
+
+fn format_project_status_update(update: &ProjectStatusUpdate) -> String {
+    let author = update.user.as_ref().map_or("unknown", |user| user.name.as_str());
+    format!(
+        "--- {} ({}, {})\n{}\n",
+        author,
+        update.health.as_deref().unwrap_or("no health"),
+        update.created_at,
+        update.body.as_deref().unwrap_or("").trim_end()
+    )
+}
+
+const DOCUMENT_FIELDS: &str = "id title url content project { id name } updatedAt";
+
+pub async fn handle_doc_create(args: &DocCreateArgs) -> Result<(), Box<dyn std::error::Error>> {
+    let mut input = Map::new();
+    input.insert("title".into(), json!(args.title));
+    input.insert("projectId".into(), json!(resolve::resolve_project(&args.project).await?));
+    if let Some(content) = content_arg(args.content.as_deref(), args.content_file.as_deref())? {
+        input.insert("content".into(), json!(content));
+    }
+
+    let data = api::mutate_linear::<DocumentCreateResponse>(
+        &format!(
+            "mutation DocumentCreate($input: DocumentCreateInput!) {{ documentCreate(input: $input) {{ success document {{ {} }} }} }}",
+            DOCUMENT_FIELDS
+        ),
+        Some(json!({ "input": input })),
+    )
+    .await?;
+
+    if !data.document_create.success {
+        return Err("[error] document creation failed".into());
+    }
+    let document = data
+        .document_create
+        .document
+        .ok_or("document creation reported success but no document data returned")?;
+    print_document("document created!", &document);
+    Ok(())
+}
+
+pub async fn handle_doc_view(document: &str) -> Result<(), Box<dyn std::error::Error>> {
+    let document = fetch_document(document).await?;
+    print!("{}", format_document_detail(&document));
+    Ok(())
+}
+
+pub async fn handle_doc_update(args: &DocUpdateArgs) -> Result<(), Box<dyn std::error::Error>> {
+    let mut input = Map::new();
+    if let Some(title) = &args.title {
+        input.insert("title".into(), json!(title));
+    }
+    if let Some(project) = &args.project {
+        input.insert("projectId".into(), json!(resolve::resolve_project(project).await?));
+    }
+    if let Some(content) = content_arg(args.content.as_deref(), args.content_file.as_deref())? {
+        input.insert("content".into(), json!(content));
+    }
+    if input.is_empty() {
+        return Err("nothing to update: pass at least one field".into());
+    }
+
+    let data = api::mutate_linear::<DocumentUpdateResponse>(
+        &format!(
+            "mutation DocumentUpdate($id: String!, $input: DocumentUpdateInput!) {{ documentUpdate(id: $id, input: $input) {{ success document {{ {} }} }} }}",
+            DOCUMENT_FIELDS
+        ),
+        Some(json!({ "id": resolve::document_id(&args.document), "input": input })),
+    )
+    .await?;
+
+    if !data.document_update.success {
+        return Err("[error] document update failed".into());
+    }
+    let document = data
+        .document_update
+        .document
+        .ok_or("document update reported success but no document data returned")?;
+    print_document("document updated!", &document);
+    Ok(())
+}
+
+pub async fn handle_doc_list(project: Option<&str>) -> Result<(), Box<dyn std::error::Error>> {
+    let mut filter = Map::new();
+    if let Some(project) = project {
+        let project_id = resolve::resolve_project(project).await?;
+        filter.insert("project".into(), json!({ "id": { "eq": project_id } }));
+    }
+
+    let data = api::query_linear::<DocumentsResponse>(
+        "query Documents($filter: DocumentFilter) { documents(first: 250, filter: $filter) { \
+         nodes { id title url project { id name } updatedAt } } }",
+        Some(json!({ "filter": filter })),
+    )
+    .await?;
+
+    for document in &data.documents.nodes {
+        println!(
+            "{}\t{}\t{}\t{}\t{}",
+            document.title,
+            document.project.as_ref().map_or("-", |project| project.name.as_str()),
+            document.updated_at.as_deref().unwrap_or("-"),
+            document.url,
+            document.id
+        );
+    }
+    Ok(())
+}
+
+pub async fn handle_doc_export(document: &str, output: Option<&Path>) -> Result<(), Box<dyn std::error::Error>> {
+    let document = fetch_document(document).await?;
+    let markdown = document_markdown(&document);
+
+    match output {
+        Some(path) if api::is_dry_run() => println!("would write {} ({} bytes)", path.display(), markdown.len()),
+        Some(path) => {
+            fs::write(path, markdown)?;
+            println!("exported {} to {}", document.title, path.display());
+        }
+        None => print!("{}", markdown),
+    }
+    Ok(())
+}
+
+async fn fetch_document(document: &str) -> Result<DocumentSummary, Box<dyn std::error::Error>> {
+    let data = api::query_linear::<DocumentResponse>(
+        &format!("query Document($id: String!) {{ document(id: $id) {{ {} }} }}", DOCUMENT_FIELDS),
+        Some(json!({ "id": resolve::document_id(document) })),
+    )
+    .await
+    .map_err(|e| format!("document not found: {} ({})", document, e))?;
+    Ok(data.document)
+}
+
+/// `--content`, or the contents of `--content-file` (`-` for stdin).
+fn content_arg(
+    content: Option<&str>,
+    content_file: Option<&Path>,
+) -> Result<Option<String>, Box<dyn std::error::Error>> {
+    match content_file {
+        Some(path) => Ok(Some(read_input(path)?)),
+        None => Ok(content.map(String::from)),
+    }
+}
+
+fn print_document(heading: &str, document: &DocumentSummary) {
+    println!("{}", heading);
+    println!("id: {}", document.id);
+    println!("title: {}", document.title);
+    println!("url: {}", document.url);
+}
+
+fn format_document_detail(document: &DocumentSummary) -> String {
+    let mut out = format!("{}\n", document.title);
+    if let Some(project) = &document.project {
+        out += &format!("project: {}\n", project.name);
+    }
+    if let Some(updated_at) = &document.updated_at {
+        out += &format!("updated: {}\n", updated_at);
+    }
+    out += &format!("url: {}\n", document.url);
+    out += &format!("id: {}\n", document.id);
+    if let Some(content) = document.content.as_deref().filter(|c| !c.trim().is_empty()) {
+        out += &format!("\n{}\n", content.trim_end());
+    }
+    out
+}
+
+fn document_markdown(document: &DocumentSummary) -> String {
+    match document.content.as_deref().map(str::trim_end).filter(|c| !c.is_empty()) {
+        Some(content) => format!("# {}\n\n{}\n", document.title, content),
+        None => format!("# {}\n", document.title),
+    }
+}
+
+pub async fn handle_list_initiatives() -> Result<(), Box<dyn std::error::Error>> {
+    for initiative in resolve::initiatives().await? {
+        println!(
+            "{}\t{}\t{}\t{}\t{}",
+            initiative.name,
+            initiative.status.as_deref().unwrap_or("-"),
+            initiative.owner.as_ref().map_or("-", |owner| owner.name.as_str()),
+            initiative.target_date.as_deref().unwrap_or("-"),
+            initiative.id
+        );
+    }
+    Ok(())
+}
+
+pub async fn handle_initiative_view(initiative: &str) -> Result<(), Box<dyn std::error::Error>> {
+    let initiative_id = resolve::resolve_initiative(initiative).await?;
+    let data = api::query_linear::<InitiativeDetailResponse>(
+        "query Initiative($id: String!) { initiative(id: $id) { \
+         id name description status targetDate url owner { id name } \
+         projects(first: 250) { nodes { id name state progress targetDate } } } }",
+        Some(json!({ "id": initiative_id })),
+    )
+    .await?;
+
+    print!("{}", format_initiative_detail(&data.initiative));
+    Ok(())
+}
+
+fn format_initiative_detail(initiative: &InitiativeDetail) -> String {
+    let mut out = format!("{}\n", initiative.name);
+    if let Some(status) = &initiative.status {
+        out += &format!("status: {}\n", status);
+    }
+    out += &format!(
+        "owner: {}\n",
+        initiative.owner.as_ref().map_or("none", |owner| owner.name.as_str())
+    );
+    if let Some(date) = &initiative.target_date {
+        out += &format!("target date: {}\n", date);
+    }
+    if let Some(url) = &initiative.url {
+        out += &format!("url: {}\n", url);
+    }
+    out += &format!("id: {}\n", initiative.id);
+
+    if let Some(description) = initiative.description.as_deref().filter(|d| !d.trim().is_empty()) {
+        out += &format!("\n{}\n", description.trim_end());
+    }
+
+    if !initiative.projects.nodes.is_empty() {
+        out += "\nprojects:\n";
+        for project in &initiative.projects.nodes {
+            out += &format!(
+                "  {}\t{}\t{}\t{}\t{}\n",
+                project.name,
+                project.state.as_deref().unwrap_or("-"),
+                project
+                    .progress
+                    .map_or("-".to_string(), |progress| format!("{:.0}%", progress * 100.0)),
+                project.target_date.as_deref().unwrap_or("-"),
+                project.id
+            );
+        }
+    }
+    out
+}
+
+pub async fn handle_initiative_add_project(
+    initiative: &str,
+    project: &str,
+) -> Result<(), Box<dyn std::error::Error>> {
+    let initiative_id = resolve::resolve_initiative(initiative).await?;
+    let project_id = resolve::resolve_project(project).await?;
+
+    let data = api::mutate_linear::<InitiativeToProjectCreateResponse>(
+        "mutation InitiativeToProjectCreate($input: InitiativeToProjectCreateInput!) { \
+         initiativeToProjectCreate(input: $input) { success } }",
+        Some(json!({ "input": { "initiativeId": initiative_id, "projectId": project_id } })),
+    )
+    .await?;
+
+    if !data.initiative_to_project_create.success {
+        return Err("[error] linking the project to the initiative failed".into());
+    }
+    println!("project {} linked to initiative {}", project, initiative);
+    Ok(())
+}
+
+/// Resolves the fields shared by `project create` and `project update`.
+async fn project_input(fields: &ProjectFieldsArgs) -> Result<Map<String, Value>, Box<dyn std::error::Error>> {
+    let mut input = Map::new();
+    if let Some(description) = &fields.description {
+        input.insert("description".into(), json!(description));
+    }
+    let content = match &fields.content_file {
+        Some(path) => Some(read_input(path)?),
+        None => fields.content.clone(),
+    };
+    if let Some(content) = content {
+        input.insert("content".into(), json!(content));
+    }
+    if let Some(lead) = &fields.lead {
+        input.insert("leadId".into(), json!(resolve::resolve_user(lead).await?));
+    }
+    if let Some(date) = &fields.start_date {
+        input.insert("startDate".into(), json!(resolve::parse_date(date)?));
+    }
+    if let Some(date) = &fields.target_date {
+        input.insert("targetDate".into(), json!(resolve::parse_date(date)?));
+    }
+    if let Some(icon) = &fields.icon {
+        input.insert("icon".into(), json!(icon));
+    }
+    if let Some(color) = &fields.color {
+        input.insert("color".into(), json!(color));
+    }
+    Ok(input)
+}
+
+async fn resolve_teams(values: &[String]) -> Result<Vec<String>, Box<dyn std::error::Error>> {
+    let mut ids = Vec::with_capacity(values.len());
+    for value in values {
+        ids.push(resolve::resolve_team(value).await?);
+    }
+    Ok(ids)
+}
+
+pub async fn handle_milestone_list(project: &str) -> Result<(), Box<dyn std::error::Error>> {
+    let project_id = resolve::resolve_project(project).await?;
+
+    for milestone in resolve::project_milestones(&project_id).await? {
+        println!(
+            "{}\t{}\t{}",
+            milestone.name,
+            milestone.target_date.as_deref().unwrap_or("-"),
+            milestone.id
+        );
+    }
+    Ok(())
+}
+
+pub async fn handle_milestone_create(args: &MilestoneCreateArgs) -> Result<(), Box<dyn std::error::Error>> {
+    let mut input = Map::new();
+    input.insert("projectId".into(), json!(resolve::resolve_project(&args.project).await?));
+    input.insert("name".into(), json!(args.name));
+    if let Some(date) = &args.target_date {
+        input.insert("targetDate".into(), json!(resolve::parse_date(date)?));
+    }
+    if let Some(description) = &args.description {
+        input.insert("description".into(), json!(description));
+    }
+
+    let data = api::mutate_linear::<ProjectMilestoneCreateResponse>(
+        "mutation ProjectMilestoneCreate($input: ProjectMilestoneCreateInput!) { \
+         projectMilestoneCreate(input: $input) { success projectMilestone { id name targetDate } } }",
+        Some(json!({ "input": input })),
+    )
+    .await?;
+
+    if !data.project_milestone_create.success {
+        return Err("[error] milestone creation failed".into());
+    }
+    let milestone = data
+        .project_milestone_create
+        .project_milestone
+        .ok_or("milestone creation reported success but no milestone data returned")?;
+    println!("milestone created!");
+    println!("id: {}", milestone.id);
+    println!("name: {}", milestone.name);
+    if let Some(date) = &milestone.target_date {
+        println!("target date: {}", date);
+    }
+    Ok(())
+}
+
+fn print_project(heading: &str, project: &Project) {
+    println!("{}", heading);
+    println!("id: {}", project.id);
+    println!("name: {}", project.name);
+    if let Some(url) = &project.url {
+        println!("url: {}", url);
+    }
+}
+
+fn format_project_detail(project: &ProjectDetail) -> String {
+    let mut out = format!("{}\n", project.name);
+    if let Some(state) = &project.state {
+        out += &format!("state: {}\n", state);
+    }
+    if let Some(progress) = project.progress {
+        out += &format!("progress: {:.0}%\n", progress * 100.0);
+    }
+    out += &format!("lead: {}\n", project.lead.as_ref().map_or("none", |lead| lead.name.as_str()));
+    if let Some(date) = &project.start_date {
+        out += &format!("start date: {}\n", date);
+    }
+    if let Some(date) = &project.target_date {
+        out += &format!("target date: {}\n", date);
+    }
+    let teams: Vec<&str> = project
+        .teams
+        .nodes
+        .iter()
+        .map(|team| team.key.as_deref().or(team.name.as_deref()).unwrap_or(&team.id))
+        .collect();
+    out += &format!("teams: {}\n", teams.join(", "));
+    let members: Vec<&str> = project.members.nodes.iter().map(|member| member.name.as_str()).collect();
+    if !members.is_empty() {
+        out += &format!("members: {}\n", members.join(", "));
+    }
+    out += &format!("issues: {}\n", issue_counts(&project.issues.nodes));
+    out += &format!("url: {}\n", project.url);
+    out += &format!("id: {}\n", project.id);
+
+    if let Some(description) = project.description.as_deref().filter(|d| !d.trim().is_empty()) {
+        out += &format!("\n{}\n", description.trim_end());
+    }
+
+    if !project.project_milestones.nodes.is_empty() {
+        out += "\nmilestones:\n";
+        for milestone in &project.project_milestones.nodes {
+            out += &format!(
+                "  {}\t{}\t{}\n",
+                milestone.name,
+                milestone.target_date.as_deref().unwrap_or("-"),
+                milestone.id
+            );
+        }
+    }
+
+    if let Some(content) = project.content.as_deref().filter(|c| !c.trim().is_empty()) {
+        out += &format!("\n{}\n", content.trim_end());
+    }
+    out
+}
+
+/// `12 (3 started, 5 unstarted, 4 completed)`, in workflow order.
+fn issue_counts(issues: &[IssueStateNode]) -> String {
+    let mut counts: Vec<(&str, usize)> = Vec::new();
+    for issue in issues {
+        let state_type = issue.state.as_ref().map_or("unknown", |state| state.state_type.as_str());
+        match counts.iter_mut().find(|(name, _)| *name == state_type) {
+            Some((_, count)) => *count += 1,
+            None => counts.push((state_type, 1)),
+        }
+    }
+    if counts.is_empty() {
+        return "0".to_string();
+    }
+    counts.sort_by_key(|(state_type, _)| {
+        STATE_TYPE_ORDER
+            .iter()
+            .position(|t| t == state_type)
+            .unwrap_or(STATE_TYPE_ORDER.len())
+    });
+    let parts: Vec<String> = counts
+        .iter()
+        .map(|(state_type, count)| format!("{} {}", count, state_type))
+        .collect();
+    format!("{} ({})", issues.len(), parts.join(", "))
+}
+
+pub async fn handle_search(args: &SearchArgs) -> Result<(), Box<dyn std::error::Error>> {
+    if args.kind != SearchKind::Issues && !args.filters.is_empty() {
+        return Err("--team, --state, --assignee, --project and --label only apply to issue search".into());
+    }
+
+    let variables = json!({
+        "term": args.query,
+        "first": args.limit,
+        "includeArchived": args.include_archived,
+    });
+
+    match args.kind {
+        SearchKind::Issues => {
+            let mut variables = variables;
+            variables["filter"] = filter::issue_filter(&args.filters).await?;
+            let data = api::query_linear::<IssueSearchResponse>(
+                "query SearchIssues($term: String!, $first: Int, $includeArchived: Boolean, $filter: IssueFilter) { \
+                 searchIssues(term: $term, first: $first, includeArchived: $includeArchived, filter: $filter) { \
+                 nodes { id identifier title url state { name type } description } } }",
+                Some(variables),
+            )
+            .await?;
+            for issue in data.search_issues.nodes {
+                let state = issue.state.as_ref().map_or("-", |state| state.name.as_str());
+                print_search_row(&issue.identifier, &issue.title, state, issue.description.as_deref(), &args.query);
+            }
+        }
+        SearchKind::Documents => {
+            let data = api::query_linear::<DocumentSearchResponse>(
+                "query SearchDocuments($term: String!, $first: Int, $includeArchived: Boolean) { \
+                 searchDocuments(term: $term, first: $first, includeArchived: $includeArchived) { \
+                 nodes { id title url content } } }",
+                Some(variables),
+            )
+            .await?;
+            for document in data.search_documents.nodes {
+                print_search_row(&document.url, &document.title, "-", document.content.as_deref(), &args.query);
+            }
+        }
+        SearchKind::Projects => {
+            let data = api::query_linear::<ProjectSearchResponse>(
+                "query SearchProjects($term: String!, $first: Int, $includeArchived: Boolean) { \
+                 searchProjects(term: $term, first: $first, includeArchived: $includeArchived) { \
+                 nodes { id name url description state } } }",
+                Some(variables),
+            )
+            .await?;
+            for project in data.search_projects.nodes {
+                let state = project.state.as_deref().unwrap_or("-");
+                print_search_row(&project.url, &project.name, state, project.description.as_deref(), &args.query);
+            }
+        }
+    }
+
+    Ok(())
+}
+
+fn print_search_row(key: &str, title: &str, state: &str, text: Option<&str>, query: &str) {
+    let snippet = text.and_then(|text| search::snippet(text, query));
+    println!("{}\t{}\t{}\t{}", key, title, state, snippet.as_deref().unwrap_or("-"));
+}
+
+pub async fn handle_todos_scan(args: &TodosScanArgs) -> Result<(), Box<dyn std::error::Error>> {
+    let (root, files) = todos::list_files(&args.path)?;
+    let found = todos::scan(&root, &files);
+
+    if !args.create {
+        for todo in &found {
+            println!(
+                "{}:{}\t{}\t{}\t{}",
+                todo.path,
+                todo.line,
+                todo.tag,
+                todo.reference.as_deref().unwrap_or("-"),
+                todo.text
+            );
+        }
+        return Ok(());
+    }
+
+    let web_url = git::run_in(&root, &["remote", "get-url", "origin"])
+        .ok()
+        .and_then(|remote| git::web_url(&remote));
+    let commit = git::run_in(&root, &["rev-parse", "HEAD"]).ok();
+
+    let mut created: BTreeMap<String, Vec<(usize, String)>> = BTreeMap::new();
+    let mut modified: BTreeMap<String, bool> = BTreeMap::new();
+    let mut failures = 0;
+    for todo in found.iter().filter(|todo| todo.reference.is_none()) {
+        let content = fs::read_to_string(root.join(&todo.path))?;
+        let permalink = match (&web_url, &commit) {
+            (Some(web_url), Some(commit)) => {
+                // Line numbers come from the working tree, so they only match HEAD for clean files.
+                let dirty = *modified.entry(todo.path.clone()).or_insert_with(|| {
+                    let dirty = git::run_in(&root, &["status", "--porcelain", "--", &todo.path])
+                        .map_or(true, |status| !status.is_empty());
+                    if dirty {
+                        eprintln!("[warning] {} has uncommitted changes; not linking to it", todo.path);
+                    }
+                    dirty
+                });
+                (!dirty).then(|| format!("{}/blob/{}/{}#L{}", web_url, commit, todo.path, todo.line))
+            }
+            _ => None,
+        };
+        let spec = IssueSpec {
+            title: Some(todo_title(todo)),
+            description: Some(todo_description(todo, &content, permalink.as_deref())),
+            team: args.team_id.clone(),
+            project: args.project_id.clone(),
+            labels: args.labels.clone(),
+            idempotency_key: Some(todo_idempotency_key(todo)),
+            ..Default::default()
+        };
+
+        match create_from_spec(&spec).await {
+            Ok((issue, existing)) => {
+                let identifier = issue.identifier.unwrap_or(issue.id);
+                let action = if existing { "existing" } else { "created" };
+                println!("{}\t{}\t{}:{}\t{}", action, identifier, todo.path, todo.line, todo.text);
+                created.entry(todo.path.clone()).or_default().push((todo.line, identifier));
+            }
+            Err(e) if e.is::<api::DryRun>() => {}
+            Err(e) => {
+                failures += 1;
+                eprintln!("[error] {}:{}: {}", todo.path, todo.line, e);
+            }
+        }
+    }
+
+    if args.rewrite && !api::is_dry_run() {
+        for (path, lines) in &created {
+            let file = root.join(path);
+            let content = fs::read_to_string(&file)?;
+            fs::write(&file, rewrite_todos(&content, lines))?;
+        }
+    }
+
+    if failures > 0 {
+        return Err(format!("{} TODOs could not be filed", failures).into());
+    }
+    Ok(())
+}
+
+fn todo_title(todo: &Todo) -> String {
+    if todo.text.is_empty() {
+        return format!("{} in {}:{}", todo.tag, todo.path, todo.line);
+    }
+    let mut title: String = todo.text.chars().take(100).collect();
+    if title.len() < todo.text.len() {
+        title.push('…');
+    }
+    title
+}
+
+/// Includes the line so identical comments in one file stay separate issues.
+fn todo_idempotency_key(todo: &Todo) -> String {
+    format!("todo:{}:{}:{}:{}", todo.path, todo.line, todo.tag, todo.text)
+}
+
+fn todo_description(todo: &Todo, content: &str, permalink: Option<&str>) -> String {
+    let location = format!("{}:{}", todo.path, todo.line);
+    let location = match permalink {
+        Some(permalink) => format!("[`{}`]({})", location, permalink),
+        None => format!("`{}`", location),
+    };
+    let language = Path::new(&todo.path)
+        .extension()
+        .and_then(|ext| ext.to_str())
+        .unwrap_or("");
+
+    format!(
+        "`{}` comment found in {}\n\n```{}\n{}\n```",
+        todo.tag,
+        location,
+        language,
+        todos::context(content, todo.line, 3)
+    )
+}
+
+fn rewrite_todos(content: &str, lines: &[(usize, String)]) -> String {
+    let rewritten: Vec<String> = content
+        .split('\n')
+        .enumerate()
+        .map(|(index, line)| match lines.iter().find(|(number, _)| *number == index + 1) {
+            Some((_, identifier)) => todos::rewrite_line(line, identifier),
+            None => line.to_string(),
+        })
+        .collect();
+    rewritten.join("\n")
+}
+
+pub async fn handle_todos_check(path: &Path) -> Result<(), Box<dyn std::error::Error>> {
+    let (root, files) = todos::list_files(path)?;
+    let found = todos::scan(&root, &files);
+
+    let mut references: Vec<&str> = found.iter().filter_map(|todo| todo.reference.as_deref()).collect();
+    references.sort();
+    references.dedup();
+
+    let states: BTreeMap<&str, Result<IssueDetail, String>> = stream::iter(references)
+        .map(|identifier| async move { (identifier, fetch_issue(identifier).await.map_err(|e| e.to_string())) })
+        .buffer_unordered(8)
+        .collect()
+        .await;
+
+    let mut stale = 0;
+    for todo in &found {
+        let Some(reference) = todo.reference.as_deref() else {
+            continue;
+        };
+        let problem = match &states[reference] {
+            Ok(issue) => issue
+                .state
+                .as_ref()
+                .filter(|state| state.state_type == "completed" || state.state_type == "canceled")
+                .map(|state| format!("{} ({})", state.name, state.state_type)),
+            Err(e) => Some(format!("not found: {}", e)),
+        };
+        if let Some(problem) = problem {
+            stale += 1;
+            println!("{}:{}\t{}\t{}", todo.path, todo.line, reference, problem);
+        }
+    }
+
+    if stale > 0 {
+        return Err(format!("{} TODOs reference closed or missing issues", stale).into());
+    }
+    Ok(())
+}
+
+/// Reads a file, or stdin when the path is `-`.
+fn read_input(path: &Path) -> Result<String, Box<dyn std::error::Error>> {
+    if path.as_os_str() == "-" {
+        let mut buffer = String::new();
+        io::stdin().read_to_string(&mut buffer)?;
+        return Ok(buffer);
+    }
+    Ok(fs::read_to_string(path)?)
+}
+
+const ISSUE_DETAIL_QUERY: &str = r#"
+        query Issue($id: String!) {
+            issue(id: $id) {
+                id
+                identifier
+                title
+                url
+                branchName
+                description
+                priorityLabel
+                team { id key name }
+                state { name type }
+                assignee { id name }
+                project { id name }
+                labels { nodes { name } }
+                comments(first: 50) { nodes { body createdAt user { id name } } }
+            }
+        }
+    "#;
+
+async fn fetch_issue(id: &str) -> Result<IssueDetail, Box<dyn std::error::Error>> {
+    let data = api::query_linear::<IssueResponse>(ISSUE_DETAIL_QUERY, Some(json!({ "id": id }))).await?;
+    Ok(data.issue)
+}
+
+fn format_issue_detail(issue: &IssueDetail) -> String {
+    let mut out = format!("{}: {}\n", issue.identifier, issue.title);
+    if let Some(state) = &issue.state {
+        out += &format!("state: {}\n", state.name);
+    }
+    if let Some(priority) = &issue.priority_label {
+        out += &format!("priority: {}\n", priority);
+    }
+    out += &format!(
+        "assignee: {}\n",
+        issue.assignee.as_ref().map_or("unassigned", |user| user.name.as_str())
+    );
+    if let Some(team) = issue.team.name.as_ref().or(issue.team.key.as_ref()) {
+        out += &format!("team: {}\n", team);
+    }
+    if let Some(project) = &issue.project {
+        out += &format!("project: {}\n", project.name);
+    }
+    if let Some(labels) = &issue.labels
+        && !labels.nodes.is_empty()
+    {
+        let names: Vec<&str> = labels.nodes.iter().map(|label| label.name.as_str()).collect();
+        out += &format!("labels: {}\n", names.join(", "));
+    }
+    out += &format!("url: {}\n", issue.url);
+    if let Some(branch_name) = &issue.branch_name {
+        out += &format!("branch name: {}\n", branch_name);
+    }
+
+    if let Some(description) = issue.description.as_deref().filter(|d| !d.trim().is_empty()) {
+        out += &format!("\n{}\n", description.trim_end());
+    }
+
+    if let Some(comments) = &issue.comments
+        && !comments.nodes.is_empty()
+    {
+        out += "\ncomments:\n";
+        for comment in &comments.nodes {
+            let author = comment.user.as_ref().map_or("unknown", |user| user.name.as_str());
+            out += &format!("--- {} ({})\n{}\n", author, comment.created_at, comment.body.trim_end());
         }
-    });
+    }
+
+    out
+}
+
+fn checkout_issue_branch(issue: &Issue) -> Result<(), Box<dyn std::error::Error>> {
+    let branch = match (&issue.branch_name, &issue.identifier) {
+        (Some(branch), _) => branch.clone(),
+        (None, Some(identifier)) => git::default_branch_name(identifier, &issue.title),
+        (None, None) => return Err("no branch name available for this issue".into()),
+    };
+    switch_to_branch(&branch)
+}
 
-    let data = api::query_linear::<IssueCreateResponse>(mutation, Some(variables)).await?;
+fn switch_to_branch(branch: &str) -> Result<(), Box<dyn std::error::Error>> {
+    match git::switch_branch(branch)? {
+        git::BranchSource::Local => println!("switched to branch: {}", branch),
+        git::BranchSource::Remote => println!("created branch: {} (tracking the remote branch)", branch),
+        git::BranchSource::New => println!("created branch: {}", branch),
+    }
+    Ok(())
+}
+
+async fn handle_create_from_path(
+    path: &Path,
+    args: &CreateArgs,
+) -> Result<(), Box<dyn std::error::Error>> {
+    if !path.is_dir() {
+        let (action, issue) = sync_markdown_issue(path, args).await?;
+        print_issue(&format!("issue {}!", action), &issue);
+        return Ok(());
+    }
 
-    if data.issue_create.success {
-        if let Some(issue) = data.issue_create.issue {
-            println!("issue created!");
-            println!("id: {}", issue.id);
-            println!("title: {}", issue.title);
-            println!("url: {}", issue.url);
-            if let Some(branch_name) = &issue.branch_name {
-                println!("branch name: {}", branch_name);
-            } else {
-                println!("branch name: not available");
+    let mut files: Vec<PathBuf> = fs::read_dir(path)?
+        .filter_map(|entry| entry.ok().map(|entry| entry.path()))
+        .filter(|file| file.extension().is_some_and(|ext| ext == "md"))
+        .collect();
+    files.sort();
+
+    let mut failures = 0;
+    for file in &files {
+        match sync_markdown_issue(file, args).await {
+            Ok((action, issue)) => println!(
+                "{}\t{}\t{}",
+                action,
+                issue.identifier.as_deref().unwrap_or(&issue.id),
+                file.display()
+            ),
+            Err(e) if e.is::<api::DryRun>() => {}
+            Err(e) => {
+                failures += 1;
+                eprintln!("[error] {}: {}", file.display(), e);
             }
-        } else {
-            eprintln!("[warning] issue creation reported success but no issue data returned");
         }
-    } else {
-        return Err("[error] issue creation failed".into());
     }
 
+    if failures > 0 {
+        return Err(format!("{} of {} files failed", failures, files.len()).into());
+    }
+    Ok(())
+}
+
+/// Creates the issue described by a markdown file, or updates it when the
+/// front matter already carries an `id`, and writes the identifier back.
+async fn sync_markdown_issue(
+    path: &Path,
+    args: &CreateArgs,
+) -> Result<(&'static str, Issue), Box<dyn std::error::Error>> {
+    let content = fs::read_to_string(path)?;
+    let doc = frontmatter::parse(&content)?;
+    let front_matter = &doc.front_matter;
+
+    let (title, description) = split_title(front_matter.get("title"), &doc.body)
+        .ok_or("no title: add `title:` to the front matter or a `# heading` to the body")?;
+
+    let spec = IssueSpec {
+        title: Some(title),
+        description: Some(description),
+        team: front_matter.get("team").or(args.team_id.as_deref()).map(String::from),
+        project: front_matter.get("project").or(args.project_id.as_deref()).map(String::from),
+        labels: front_matter.get_list("labels"),
+        priority: front_matter.get("priority").map(String::from),
+        assignee: front_matter.get("assignee").map(String::from),
+        subscribers: front_matter.get_list("subscribers"),
+        milestone: front_matter.get("milestone").map(String::from),
+        parent: front_matter.get("parent").map(String::from),
+        estimate: front_matter.get("estimate").map(String::from),
+        idempotency_key: None,
+    };
+    let input = build_issue_input(&spec).await?;
+
+    let existing = front_matter.get("id");
+    let (action, issue) = match existing {
+        Some(id) => ("updated", update_issue(json!({ "id": id, "input": input })).await?),
+        None => ("created", create_issue(json!({ "input": input })).await?),
+    };
+    let issue = issue.ok_or_else(|| format!("issue {} but no issue data returned", action))?;
+
+    if let Some(identifier) = &issue.identifier
+        && existing != Some(identifier.as_str())
+    {
+        fs::write(path, frontmatter::set_field(&content, "id", identifier))?;
+    }
+
+    Ok((action, issue))
+}
+
+/// Resolves the human-friendly names of an [`IssueSpec`] into an `IssueCreateInput`.
+async fn build_issue_input(spec: &IssueSpec) -> Result<Map<String, Value>, Box<dyn std::error::Error>> {
+    let title = spec.title.as_deref().ok_or("missing title")?;
+    let team = spec
+        .team
+        .as_deref()
+        .ok_or("no team: add `team` to the input or pass --team-id")?;
+    let team_id = resolve::resolve_team(team).await?;
+
+    let mut input = Map::new();
+    input.insert("teamId".into(), json!(team_id));
+    input.insert("title".into(), json!(title));
+    if let Some(description) = &spec.description {
+        input.insert("description".into(), json!(description));
+    }
+    if let Some(project) = &spec.project {
+        let project_id = resolve::resolve_project(project).await?;
+        if let Some(milestone) = &spec.milestone {
+            input.insert(
+                "projectMilestoneId".into(),
+                json!(resolve::resolve_milestone(&project_id, milestone).await?),
+            );
+        }
+        input.insert("projectId".into(), json!(project_id));
+    } else if spec.milestone.is_some() {
+        return Err("milestone needs a project".into());
+    }
+    if !spec.labels.is_empty() {
+        input.insert("labelIds".into(), json!(resolve::resolve_labels(&team_id, &spec.labels).await?));
+    }
+    if let Some(priority) = &spec.priority {
+        input.insert("priority".into(), json!(resolve::parse_priority(priority)?));
+    }
+    if let Some(assignee) = &spec.assignee {
+        input.insert("assigneeId".into(), json!(resolve::resolve_user(assignee).await?));
+    }
+    if !spec.subscribers.is_empty() {
+        input.insert("subscriberIds".into(), json!(resolve_users(&spec.subscribers).await?));
+    }
+    if let Some(parent) = &spec.parent {
+        input.insert("parentId".into(), json!(resolve::resolve_issue(parent).await?));
+    }
+    if let Some(estimate) = &spec.estimate {
+        let estimate: i64 = estimate
+            .parse()
+            .map_err(|_| format!("invalid estimate: {}", estimate))?;
+        input.insert("estimate".into(), json!(estimate));
+    }
+
+    Ok(input)
+}
+
+async fn resolve_users(values: &[String]) -> Result<Vec<String>, Box<dyn std::error::Error>> {
+    let mut ids = Vec::with_capacity(values.len());
+    for value in values {
+        ids.push(resolve::resolve_user(value).await?);
+    }
+    Ok(ids)
+}
+
+/// Picks the issue title from front matter, falling back to the first
+/// `# heading` of the body, which is then dropped from the description.
+fn split_title(title: Option<&str>, body: &str) -> Option<(String, String)> {
+    if let Some(title) = title {
+        return Some((title.to_string(), body.trim().to_string()));
+    }
+
+    let mut lines = body.lines();
+    let heading = lines.by_ref().find(|line| !line.trim().is_empty())?;
+    let title = heading.strip_prefix("# ")?.trim().to_string();
+    let description = lines.collect::<Vec<_>>().join("\n").trim().to_string();
+    Some((title, description))
+}
+
+pub async fn handle_batch_create(args: &BatchCreateArgs) -> Result<(), Box<dyn std::error::Error>> {
+    let input = read_input(args.input.as_deref().unwrap_or(Path::new("-")))?;
+
+    let rows = match args.format {
+        BatchFormat::Jsonl => batch::parse_jsonl(&input),
+        BatchFormat::Csv => {
+            let (rows, ignored) = batch::parse_csv(&input, &args.map)?;
+            if !ignored.is_empty() {
+                eprintln!("[warning] ignoring CSV columns: {}", ignored.join(", "));
+            }
+            rows
+        }
+    };
+
+    let total = rows.len();
+    let mut results = stream::iter(rows)
+        .map(|(row, spec)| async move {
+            let result = match spec {
+                Ok(mut spec) => {
+                    spec.team = spec.team.or_else(|| args.team_id.clone());
+                    spec.project = spec.project.or_else(|| args.project_id.clone());
+                    match create_from_spec(&spec).await {
+                        Ok(created) => Ok(Some(created)),
+                        Err(e) if e.is::<api::DryRun>() => Ok(None),
+                        Err(e) => Err(e.to_string()),
+                    }
+                }
+                Err(e) => Err(e),
+            };
+            (row, result)
+        })
+        .buffered(args.concurrency.max(1));
+
+    let mut failures = 0;
+    while let Some((row, result)) = results.next().await {
+        let line = match result {
+            Ok(None) => json!({ "row": row, "dry_run": true }),
+            Ok(Some((issue, existing))) => json!({
+                "row": row,
+                "id": issue.id,
+                "identifier": issue.identifier,
+                "url": issue.url,
+                "existing": existing,
+            }),
+            Err(e) => {
+                failures += 1;
+                json!({ "row": row, "error": e })
+            }
+        };
+        println!("{}", line);
+    }
+
+    if failures > 0 {
+        return Err(format!("{} of {} rows failed", failures, total).into());
+    }
     Ok(())
 }
 
+/// Creates the issue for a batch row. The flag is `true` when the row's
+/// idempotency key matched an issue created earlier.
+async fn create_from_spec(spec: &IssueSpec) -> Result<(Issue, bool), Box<dyn std::error::Error>> {
+    if let Some(key) = &spec.idempotency_key
+        && let Some(issue) = find_by_idempotency_key(key).await?
+    {
+        return Ok((issue, true));
+    }
+
+    let mut input = build_issue_input(spec).await?;
+    if let Some(key) = &spec.idempotency_key {
+        let description = with_idempotency_footer(spec.description.as_deref(), key);
+        input.insert("description".into(), json!(description));
+    }
+
+    let issue = create_issue(json!({ "input": input }))
+        .await?
+        .ok_or("issue creation reported success but no issue data returned")?;
+    if let Some(key) = &spec.idempotency_key {
+        remember_idempotency_key(key, &issue);
+    }
+    Ok((issue, false))
+}
+
 pub async fn handle_list_teams() -> Result<(), Box<dyn std::error::Error>> {
-    let data =
-        api::query_linear::<TeamsResponse>("query Teams { teams { nodes { id name } } }", None)
-            .await?;
+    let data = api::query_linear::<TeamsResponse>(
+        "query Teams { teams { nodes { id name key description members(first: 250) { nodes { id name } } \
+         cyclesEnabled cycleDuration triageEnabled } } }",
+        None,
+    )
+    .await?;
 
-    for team in data.teams.nodes {
-        println!("{}\t{}", team.name, team.id);
+    for team in &data.teams.nodes {
+        println!("{}", format_team(team));
     }
 
     Ok(())
 }
 
-pub async fn handle_list_projects() -> Result<(), Box<dyn std::error::Error>> {
+fn format_team(team: &Team) -> String {
+    let members = team.members.as_ref().map_or(0, |members| members.nodes.len());
+    let cycles = match (team.cycles_enabled, team.cycle_duration) {
+        (Some(true), Some(weeks)) => format!("cycles:{}w", weeks),
+        (Some(true), None) => "cycles".to_string(),
+        _ => "no-cycles".to_string(),
+    };
+    let triage = if team.triage_enabled == Some(true) { "triage" } else { "no-triage" };
+    let description = team
+        .description
+        .as_deref()
+        .map(|d| d.split_whitespace().collect::<Vec<_>>().join(" "))
+        .filter(|d| !d.is_empty());
+
+    format!(
+        "{}\t{}\t{}\t{}\t{}\t{}\t{}",
+        team.name,
+        team.id,
+        team.key.as_deref().unwrap_or("-"),
+        members,
+        cycles,
+        triage,
+        description.as_deref().unwrap_or("-")
+    )
+}
+
+pub async fn handle_list_projects(args: &ListProjectsArgs) -> Result<(), Box<dyn std::error::Error>> {
+    let mut filter = Map::new();
+    if let Some(team) = &args.team {
+        let team_id = resolve::resolve_team(team).await?;
+        filter.insert("accessibleTeams".into(), json!({ "some": { "id": { "eq": team_id } } }));
+    }
+    if let Some(state) = &args.state {
+        filter.insert("state".into(), json!({ "eqIgnoreCase": state }));
+    }
+    if let Some(lead) = &args.lead {
+        let user_id = resolve::resolve_user(lead).await?;
+        filter.insert("lead".into(), json!({ "id": { "eq": user_id } }));
+    }
+
     let data = api::query_linear::<ProjectsResponse>(
-        "query Projects { projects { nodes { id name } } }",
+        "query Projects($filter: ProjectFilter) { projects(first: 250, filter: $filter) { nodes { \
+         id name state lead { id name } targetDate progress teams { nodes { id key name } } url } } }",
+        Some(json!({ "filter": filter })),
+    )
+    .await?;
+
+    for project in &data.projects.nodes {
+        println!("{}", format_project(project));
+    }
+
+    Ok(())
+}
+
+fn format_project(project: &Project) -> String {
+    let teams: Vec<&str> = project
+        .teams
+        .as_ref()
+        .map(|teams| {
+            teams
+                .nodes
+                .iter()
+                .map(|team| team.key.as_deref().or(team.name.as_deref()).unwrap_or(&team.id))
+                .collect()
+        })
+        .unwrap_or_default();
+
+    format!(
+        "{}\t{}\t{}\t{}\t{}\t{}\t{}\t{}",
+        project.name,
+        project.id,
+        project.state.as_deref().unwrap_or("-"),
+        project.lead.as_ref().map_or("-", |lead| lead.name.as_str()),
+        project.target_date.as_deref().unwrap_or("-"),
+        project
+            .progress
+            .map_or("-".to_string(), |progress| format!("{:.0}%", progress * 100.0)),
+        if teams.is_empty() { "-".to_string() } else { teams.join(",") },
+        project.url.as_deref().unwrap_or("-")
+    )
+}
+
+pub async fn handle_list_states(team: &str) -> Result<(), Box<dyn std::error::Error>> {
+    let team_id = resolve::resolve_team(team).await?;
+
+    for state in resolve::team_states(&team_id).await? {
+        println!(
+            "{}\t{}\t{}\t{}\t{}",
+            state.name,
+            state.state_type,
+            state.color.as_deref().unwrap_or("-"),
+            state.position,
+            state.id
+        );
+    }
+
+    Ok(())
+}
+
+const MEMBER_FIELDS: &str = "id name displayName email active admin guest";
+
+pub async fn handle_list_users() -> Result<(), Box<dyn std::error::Error>> {
+    let data = api::query_linear::<MembersResponse>(
+        &format!("query Users {{ users(first: 250, includeDisabled: true) {{ nodes {{ {} }} }} }}", MEMBER_FIELDS),
         None,
     )
     .await?;
 
-    for project in data.projects.nodes {
-        println!("{}\t{}", project.name, project.id);
+    for member in &data.users.nodes {
+        println!("{}", format_member(member));
     }
+    Ok(())
+}
 
+pub async fn handle_list_members(team: &str) -> Result<(), Box<dyn std::error::Error>> {
+    let team_id = resolve::resolve_team(team).await?;
+    let data = api::query_linear::<TeamMembersResponse>(
+        &format!(
+            "query TeamMembers($id: String!) {{ team(id: $id) {{ members(first: 250) {{ nodes {{ {} }} }} }} }}",
+            MEMBER_FIELDS
+        ),
+        Some(json!({ "id": team_id })),
+    )
+    .await?;
+
+    for member in &data.team.members.nodes {
+        println!("{}", format_member(member));
+    }
     Ok(())
 }
 
+fn format_member(member: &Member) -> String {
+    let mut flags = vec![if member.active { "active" } else { "inactive" }];
+    if member.admin {
+        flags.push("admin");
+    }
+    if member.guest {
+        flags.push("guest");
+    }
+    format!(
+        "{}\t{}\t{}\t{}\t{}",
+        member.name,
+        member.display_name.as_deref().unwrap_or("-"),
+        member.email.as_deref().unwrap_or("-"),
+        flags.join(","),
+        member.id
+    )
+}
+
 #[cfg(test)]
 mod tests {
-    use crate::types::{Issue, IssueCreateResponse, IssuePayload};
+    use super::*;
+    use crate::types::IssuePayload;
     use serde_json::json;
 
     #[tokio::test]
@@ -141,6 +2447,7 @@ mod tests {
                 success: true,
                 issue: Some(Issue {
                     id: "issue-123".to_string(),
+                    identifier: None,
                     title: "Test".to_string(),
                     url: "https://linear.app/issue-123".to_string(),
                     branch_name: Some("test-branch".to_string()),
@@ -176,6 +2483,7 @@ mod tests {
                 success: true,
                 issue: Some(Issue {
                     id: "issue-456".to_string(),
+                    identifier: None,
                     title: "Feature Issue".to_string(),
                     url: "https://linear.app/issue-456".to_string(),
                     branch_name: Some("feat/issue-456-feature-issue".to_string()),
@@ -195,6 +2503,7 @@ mod tests {
                 success: true,
                 issue: Some(Issue {
                     id: "issue-789".to_string(),
+                    identifier: None,
                     title: "Bug Issue".to_string(),
                     url: "https://linear.app/issue-789".to_string(),
                     branch_name: None,
@@ -226,6 +2535,409 @@ mod tests {
         assert!(mutation.contains("branchName"));
     }
 
+    #[test]
+    fn test_issue_mutations_include_identifier() {
+        assert!(ISSUE_CREATE_MUTATION.contains("identifier"));
+        assert!(ISSUE_UPDATE_MUTATION.contains("issueUpdate(id: $id, input: $input)"));
+    }
+
+    #[test]
+    fn test_duplicate_comment_body() {
+        assert_eq!(duplicate_comment_body("Crash", &None), "Reported again: **Crash**");
+        assert_eq!(
+            duplicate_comment_body("Crash", &Some("Stack trace".to_string())),
+            "Reported again: **Crash**\n\nStack trace"
+        );
+    }
+
+    #[test]
+    fn test_with_idempotency_footer() {
+        assert_eq!(
+            with_idempotency_footer(Some("Details\n"), "deploy-42"),
+            "Details\n\nlinearite-idempotency-key: deploy-42"
+        );
+        assert_eq!(
+            with_idempotency_footer(None, "deploy-42"),
+            "linearite-idempotency-key: deploy-42"
+        );
+    }
+
+    #[test]
+    fn test_has_idempotency_footer_matches_whole_key() {
+        let description = with_idempotency_footer(Some("Details"), "deploy-42");
+        assert!(has_idempotency_footer(&description, "deploy-42"));
+        assert!(!has_idempotency_footer(&description, "deploy-4"));
+        assert!(!has_idempotency_footer("Details", "deploy-42"));
+    }
+
+    #[test]
+    fn test_milestone_project() {
+        let issue = |project: Value| -> IssueDetail {
+            serde_json::from_value(json!({
+                "id": "issue-1",
+                "identifier": "ENG-1",
+                "title": "Login fails",
+                "url": "https://linear.app/eng-1",
+                "team": {"id": "team-1"},
+                "project": project
+            }))
+            .unwrap()
+        };
+        assert_eq!(milestone_project(&issue(json!({"id": "p-1", "name": "API v2"}))), Ok("p-1"));
+        assert_eq!(
+            milestone_project(&issue(Value::Null)),
+            Err("ENG-1 is not in a project, so it can't have a milestone".to_string())
+        );
+    }
+
+    #[test]
+    fn test_format_issue_detail() {
+        let issue: IssueDetail = serde_json::from_value(json!({
+            "id": "issue-1",
+            "identifier": "ENG-1",
+            "title": "Login fails",
+            "url": "https://linear.app/eng-1",
+            "branchName": "eng-1-login-fails",
+            "description": "Steps to reproduce\n",
+            "priorityLabel": "High",
+            "team": {"id": "team-1", "key": "ENG", "name": "Engineering"},
+            "state": {"name": "Todo", "type": "unstarted"},
+            "assignee": null,
+            "project": null,
+            "labels": {"nodes": [{"name": "bug"}, {"name": "auth"}]},
+            "comments": {"nodes": [{
+                "body": "Seen on Safari",
+                "createdAt": "2025-01-01T00:00:00.000Z",
+                "user": {"id": "user-1", "name": "Alice"}
+            }]}
+        }))
+        .unwrap();
+
+        assert_eq!(
+            format_issue_detail(&issue),
+            "ENG-1: Login fails\n\
+             state: Todo\n\
+             priority: High\n\
+             assignee: unassigned\n\
+             team: Engineering\n\
+             labels: bug, auth\n\
+             url: https://linear.app/eng-1\n\
+             branch name: eng-1-login-fails\n\
+             \n\
+             Steps to reproduce\n\
+             \n\
+             comments:\n\
+             --- Alice (2025-01-01T00:00:00.000Z)\n\
+             Seen on Safari\n"
+        );
+    }
+
+    #[test]
+    fn test_format_team() {
+        let team: Team = serde_json::from_value(json!({
+            "id": "team-1",
+            "name": "Engineering",
+            "key": "ENG",
+            "description": "Core\nplatform",
+            "members": {"nodes": [{"id": "u1", "name": "A"}, {"id": "u2", "name": "B"}]},
+            "cyclesEnabled": true,
+            "cycleDuration": 2,
+            "triageEnabled": false
+        }))
+        .unwrap();
+        assert_eq!(format_team(&team), "Engineering\tteam-1\tENG\t2\tcycles:2w\tno-triage\tCore platform");
+    }
+
+    #[test]
+    fn test_format_project() {
+        let project: Project = serde_json::from_value(json!({
+            "id": "p-1",
+            "name": "API v2",
+            "state": "started",
+            "lead": null,
+            "progress": 0.426,
+            "teams": {"nodes": [{"id": "t1", "key": "ENG"}, {"id": "t2", "name": "Web"}]},
+            "url": "https://linear.app/p"
+        }))
+        .unwrap();
+        assert_eq!(
+            format_project(&project),
+            "API v2\tp-1\tstarted\t-\t-\t43%\tENG,Web\thttps://linear.app/p"
+        );
+    }
+
+    #[test]
+    fn test_list_rows_keep_id_in_second_column() {
+        // Scripts read `cut -f2` for the id, as before the extra columns existed.
+        let team: Team = serde_json::from_value(json!({ "id": "team-1", "name": "Engineering" })).unwrap();
+        let project: Project = serde_json::from_value(json!({ "id": "p-1", "name": "API v2" })).unwrap();
+        assert_eq!(format_team(&team).split('\t').nth(1), Some("team-1"));
+        assert_eq!(format_project(&project).split('\t').nth(1), Some("p-1"));
+    }
+
+    #[test]
+    fn test_issue_counts_in_workflow_order() {
+        let issues: Vec<IssueStateNode> = serde_json::from_value(json!([
+            {"state": {"name": "Done", "type": "completed"}},
+            {"state": {"name": "Todo", "type": "unstarted"}},
+            {"state": {"name": "In Progress", "type": "started"}},
+            {"state": {"name": "Done", "type": "completed"}}
+        ]))
+        .unwrap();
+        assert_eq!(issue_counts(&issues), "4 (1 started, 1 unstarted, 2 completed)");
+        assert_eq!(issue_counts(&[]), "0");
+    }
+
+    #[test]
+    fn test_format_project_detail() {
+        let project: ProjectDetail = serde_json::from_value(json!({
+            "id": "p-1",
+            "name": "API v2",
+            "description": "Faster API",
+            "state": "started",
+            "progress": 0.5,
+            "targetDate": "2026-12-01",
+            "url": "https://linear.app/p",
+            "lead": {"id": "u1", "name": "Kade"},
+            "teams": {"nodes": [{"id": "t1", "key": "ENG"}]},
+            "members": {"nodes": []},
+            "projectMilestones": {"nodes": [{"id": "m1", "name": "Beta", "targetDate": null}]},
+            "issues": {"nodes": []}
+        }))
+        .unwrap();
+        assert_eq!(
+            format_project_detail(&project),
+            "API v2\nstate: started\nprogress: 50%\nlead: Kade\ntarget date: 2026-12-01\nteams: ENG\n\
+             issues: 0\nurl: https://linear.app/p\nid: p-1\n\nFaster API\n\nmilestones:\n  Beta\t-\tm1\n"
+        );
+    }
+
+    #[test]
+    fn test_format_project_status_update() {
+        let update: ProjectStatusUpdate = serde_json::from_value(json!({
+            "id": "pu-1",
+            "body": "Beta is out.\n",
+            "health": "onTrack",
+            "createdAt": "2026-10-12T09:00:00.000Z",
+            "user": {"id": "u1", "name": "Kade"}
+        }))
+        .unwrap();
+        assert_eq!(
+            format_project_status_update(&update),
+            "--- Kade (onTrack, 2026-10-12T09:00:00.000Z)\nBeta is out.\n"
+        );
+    }
+
+    fn document(content: Option<&str>) -> DocumentSummary {
+        serde_json::from_value(json!({
+            "id": "doc-1",
+            "title": "Design spec",
+            "url": "https://linear.app/acme/document/design-spec-1a2b3c",
+            "content": content,
+            "project": {"id": "p-1", "name": "API v2"}
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn test_document_markdown() {
+        assert_eq!(document_markdown(&document(Some("## Goals\n\n"))), "# Design spec\n\n## Goals\n");
+        assert_eq!(document_markdown(&document(None)), "# Design spec\n");
+    }
+
+    #[test]
+    fn test_format_document_detail() {
+        assert_eq!(
+            format_document_detail(&document(Some("Body"))),
+            "Design spec\nproject: API v2\nurl: https://linear.app/acme/document/design-spec-1a2b3c\nid: doc-1\n\nBody\n"
+        );
+    }
+
+    #[test]
+    fn test_format_initiative_detail() {
+        let initiative: InitiativeDetail = serde_json::from_value(json!({
+            "id": "i-1",
+            "name": "Self-serve onboarding",
+            "status": "Active",
+            "targetDate": "2027-03-31",
+            "owner": null,
+            "projects": {"nodes": [{"id": "p-1", "name": "API v2", "state": "started", "progress": 0.25}]}
+        }))
+        .unwrap();
+        assert_eq!(
+            format_initiative_detail(&initiative),
+            "Self-serve onboarding\nstatus: Active\nowner: none\ntarget date: 2027-03-31\nid: i-1\n\n\
+             projects:\n  API v2\tstarted\t25%\t-\tp-1\n"
+        );
+    }
+
+    #[test]
+    fn test_format_issue_row() {
+        let mut issue = summary("ENG-1", "In Progress", "started");
+        assert_eq!(format_issue_row(&issue), "ENG-1\tt\tIn Progress\t-\t-");
+        issue.priority_label = Some("High".to_string());
+        issue.assignee = serde_json::from_value(json!({ "id": "user-1", "name": "Kade" })).unwrap();
+        assert_eq!(format_issue_row(&issue), "ENG-1\tt\tIn Progress\tKade\tHigh");
+    }
+
+    #[test]
+    fn test_find_view_by_id_or_name() {
+        let views: Vec<CustomView> = serde_json::from_value(json!([
+            {"id": "view-1", "name": "My Bugs", "shared": true},
+            {"id": "view-2", "name": "Triage"}
+        ]))
+        .unwrap();
+        assert_eq!(find_view(&views, "my bugs").unwrap().id, "view-1");
+        assert_eq!(find_view(&views, "view-2").unwrap().name, "Triage");
+        assert!(find_view(&views, "Roadmap").is_none());
+    }
+
+    #[test]
+    fn test_bulk_fields() {
+        let set = vec!["State=canceled".to_string(), "assignee = none".to_string()];
+        assert_eq!(bulk_fields(&set).unwrap(), vec![("state", "canceled"), ("assignee", "none")]);
+        assert!(bulk_fields(&["state".to_string()]).unwrap_err().contains("FIELD=VALUE"));
+        assert!(bulk_fields(&["title=x".to_string()]).unwrap_err().contains("unknown field"));
+    }
+
+    #[test]
+    fn test_describe_changes() {
+        assert_eq!(
+            describe_changes(&[("state", "canceled")], &["archived".to_string()]),
+            "state=canceled, +label archived"
+        );
+    }
+
+    #[test]
+    fn test_confirmation_prompt() {
+        let issues = vec!["ENG-1".to_string(), "ENG-2".to_string()];
+        assert_eq!(
+            confirmation_prompt(IssuesAction::Delete, &issues),
+            "Delete 2 issues (ENG-1, ENG-2)? [y/N] "
+        );
+        assert_eq!(
+            confirmation_prompt(IssuesAction::Restore, &issues[..1]),
+            "Restore 1 issue (ENG-1)? [y/N] "
+        );
+    }
+
+    #[test]
+    fn test_issues_action_mutations() {
+        assert_eq!(IssuesAction::Archive.mutation(), "issueArchive");
+        assert_eq!(IssuesAction::Restore.mutation(), "issueUnarchive");
+        assert_eq!(IssuesAction::Delete.mutation(), "issueDelete");
+    }
+
+    #[test]
+    fn test_format_member_flags() {
+        let member: Member = serde_json::from_value(json!({
+            "id": "user-1",
+            "name": "Kade Smith",
+            "displayName": "kade",
+            "email": "kade@example.com",
+            "active": false,
+            "admin": true,
+            "guest": false
+        }))
+        .unwrap();
+        assert_eq!(format_member(&member), "Kade Smith\tkade\tkade@example.com\tinactive,admin\tuser-1");
+    }
+
+    fn summary(identifier: &str, state: &str, state_type: &str) -> IssueSummary {
+        serde_json::from_value(json!({
+            "id": identifier,
+            "identifier": identifier,
+            "title": "t",
+            "url": "u",
+            "state": {"name": state, "type": state_type}
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn test_group_by_state_orders_active_first() {
+        let issues = vec![
+            summary("ENG-1", "Todo", "unstarted"),
+            summary("ENG-2", "In Progress", "started"),
+            summary("ENG-3", "Todo", "unstarted"),
+            summary("ENG-4", "In Review", "started"),
+        ];
+        let groups = group_by_state(&issues);
+        let names: Vec<&str> = groups.iter().map(|(name, _)| *name).collect();
+        assert_eq!(names, vec!["In Progress", "In Review", "Todo"]);
+        let todo: Vec<&str> = groups[2].1.iter().map(|issue| issue.identifier.as_str()).collect();
+        assert_eq!(todo, vec!["ENG-1", "ENG-3"]);
+    }
+
+    fn todo(text: &str) -> Todo {
+        Todo {
+            path: "src/api.rs".to_string(),
+            line: 3,
+            tag: "TODO".to_string(),
+            reference: None,
+            text: text.to_string(),
+        }
+    }
+
+    #[test]
+    fn test_todo_title() {
+        assert_eq!(todo_title(&todo("handle timeouts")), "handle timeouts");
+        assert_eq!(todo_title(&todo("")), "TODO in src/api.rs:3");
+        assert_eq!(todo_title(&todo(&"x".repeat(120))).chars().count(), 101);
+    }
+
+    #[test]
+    fn test_todo_idempotency_key_includes_line() {
+        let first = todo("handle timeouts");
+        let second = Todo { line: 9, ..todo("handle timeouts") };
+        assert_eq!(todo_idempotency_key(&first), "todo:src/api.rs:3:TODO:handle timeouts");
+        assert_ne!(todo_idempotency_key(&first), todo_idempotency_key(&second));
+    }
+
+    #[test]
+    fn test_todo_description() {
+        let content = "a\nb\n// TODO: handle timeouts\nc\n";
+        let description = todo_description(
+            &todo("handle timeouts"),
+            content,
+            Some("https://github.com/o/r/blob/abc/src/api.rs#L3"),
+        );
+        assert_eq!(
+            description,
+            "`TODO` comment found in [`src/api.rs:3`](https://github.com/o/r/blob/abc/src/api.rs#L3)\n\n\
+             ```rs\na\nb\n// TODO: handle timeouts\nc\n```"
+        );
+        assert!(todo_description(&todo("x"), content, None).contains("in `src/api.rs:3`"));
+    }
+
+    #[test]
+    fn test_rewrite_todos_keeps_other_lines() {
+        let content = "// TODO: one\nfn a() {}\n# FIXME: two\n";
+        let rewritten = rewrite_todos(content, &[(1, "ENG-1".to_string()), (3, "ENG-2".to_string())]);
+        assert_eq!(rewritten, "// TODO(ENG-1): one\nfn a() {}\n# FIXME(ENG-2): two\n");
+    }
+
+    #[test]
+    fn test_split_title_prefers_front_matter() {
+        let (title, description) = split_title(Some("From front matter"), "\nBody text\n").unwrap();
+        assert_eq!(title, "From front matter");
+        assert_eq!(description, "Body text");
+    }
+
+    #[test]
+    fn test_split_title_uses_first_heading() {
+        let (title, description) =
+            split_title(None, "\n# Fix login redirect\n\nUsers loop forever.\n").unwrap();
+        assert_eq!(title, "Fix login redirect");
+        assert_eq!(description, "Users loop forever.");
+    }
+
+    #[test]
+    fn test_split_title_missing() {
+        assert!(split_title(None, "No heading here\n").is_none());
+        assert!(split_title(None, "").is_none());
+    }
+
     #[test]
     fn test_handle_list_teams_variable_construction() {
         // Test that the query is constructed correctly
@@ -0,0 +1,70 @@
+//! User configuration in `$XDG_CONFIG_HOME/linearite/config.toml` (falling
+//! back to `~/.config`). Saved queries live under `[queries]`:
+//!
+//! ```toml
+//! [queries]
+//! triage = "team:ENG state:triage"
+//! mine = "assignee:me state:started,unstarted"
+//! ```
+
+use serde::Deserialize;
+use std::collections::BTreeMap;
+use std::env;
+use std::fs;
+use std::io;
+use std::path::PathBuf;
+
+#[derive(Deserialize, Default)]
+pub struct Config {
+    #[serde(default)]
+    pub queries: BTreeMap<String, String>,
+}
+
+pub fn config_path() -> Option<PathBuf> {
+    match env::var_os("XDG_CONFIG_HOME") {
+        Some(dir) if !dir.is_empty() => Some(PathBuf::from(dir).join("linearite/config.toml")),
+        _ => env::var_os("HOME").map(|home| PathBuf::from(home).join(".config/linearite/config.toml")),
+    }
+}
+
+/// A missing config file is the same as an empty one.
+pub fn load() -> Result<Config, Box<dyn std::error::Error>> {
+    let Some(path) = config_path() else {
+        return Ok(Config::default());
+    };
+    match fs::read_to_string(&path) {
+        Ok(text) => parse(&text).map_err(|e| format!("{}: {}", path.display(), e).into()),
+        Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(Config::default()),
+        Err(e) => Err(format!("{}: {}", path.display(), e).into()),
+    }
+}
+
+pub fn parse(text: &str) -> Result<Config, String> {
+    toml::from_str(text).map_err(|e| e.message().to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_queries() {
+        let config = parse(
+            r#"
+            [queries]
+            triage = "team:ENG state:triage"
+            "my bugs" = 'assignee:me label:bug'
+            "#,
+        )
+        .unwrap();
+        assert_eq!(config.queries.len(), 2);
+        assert_eq!(config.queries["triage"], "team:ENG state:triage");
+        assert_eq!(config.queries["my bugs"], "assignee:me label:bug");
+    }
+
+    #[test]
+    fn test_parse_empty_and_invalid() {
+        assert!(parse("").unwrap().queries.is_empty());
+        assert!(parse("[queries]\ntriage = 1").is_err());
+    }
+}
@@ -0,0 +1,141 @@
+use crate::api;
+use crate::types::{IssueSearchResponse, IssueSummary, IssuesResponse};
+use serde_json::{Value, json};
+use std::collections::BTreeSet;
+
+pub const DEFAULT_THRESHOLD: f64 = 0.6;
+
+const STOP_WORDS: &[&str] = &[
+    "a", "an", "and", "are", "as", "at", "be", "by", "for", "from", "in", "is", "it", "of", "on",
+    "or", "the", "to", "when", "with",
+];
+
+const ISSUE_FIELDS: &str = "id identifier title url state { name type }";
+
+pub struct Duplicate {
+    pub issue: IssueSummary,
+    pub score: f64,
+}
+
+/// Looks for open issues in the team whose titles are similar to `title`,
+/// combining a scan of the team's open issues with Linear's full-text search.
+/// Best matches come first.
+pub async fn find_duplicates(
+    team_id: &str,
+    title: &str,
+    threshold: f64,
+) -> Result<Vec<Duplicate>, Box<dyn std::error::Error>> {
+    let filter = open_issues_filter(team_id);
+
+    let open = api::query_linear::<IssuesResponse>(
+        &format!(
+            "query OpenIssues($filter: IssueFilter) {{ issues(first: 250, filter: $filter) {{ nodes {{ {} }} }} }}",
+            ISSUE_FIELDS
+        ),
+        Some(json!({ "filter": filter })),
+    )
+    .await?;
+
+    let mut candidates = open.issues.nodes;
+    match api::query_linear::<IssueSearchResponse>(
+        &format!(
+            "query SearchIssues($term: String!, $filter: IssueFilter) {{ searchIssues(term: $term, filter: $filter, first: 20) {{ nodes {{ {} }} }} }}",
+            ISSUE_FIELDS
+        ),
+        Some(json!({ "term": title, "filter": filter })),
+    )
+    .await
+    {
+        Ok(search) => candidates.extend(search.search_issues.nodes),
+        Err(e) => eprintln!("[warning] full-text search failed, using title matching only: {}", e),
+    }
+
+    let mut seen = BTreeSet::new();
+    let mut duplicates: Vec<Duplicate> = candidates
+        .into_iter()
+        .filter(|issue| seen.insert(issue.id.clone()))
+        .map(|issue| {
+            let score = similarity(title, &issue.title);
+            Duplicate { issue, score }
+        })
+        .filter(|duplicate| duplicate.score >= threshold)
+        .collect();
+    duplicates.sort_by(|a, b| b.score.total_cmp(&a.score));
+
+    Ok(duplicates)
+}
+
+fn open_issues_filter(team_id: &str) -> Value {
+    json!({
+        "team": { "id": { "eq": team_id } },
+        "state": { "type": { "nin": ["completed", "canceled"] } },
+    })
+}
+
+/// Dice coefficient over normalized title tokens, from 0.0 to 1.0.
+pub fn similarity(a: &str, b: &str) -> f64 {
+    let a = tokens(a);
+    let b = tokens(b);
+    if a.is_empty() || b.is_empty() {
+        return 0.0;
+    }
+    let shared = a.intersection(&b).count();
+    (2 * shared) as f64 / (a.len() + b.len()) as f64
+}
+
+fn tokens(title: &str) -> BTreeSet<String> {
+    title
+        .to_lowercase()
+        .split(|c: char| !c.is_alphanumeric())
+        .filter(|token| !token.is_empty() && !STOP_WORDS.contains(token))
+        .map(stem)
+        .collect()
+}
+
+fn stem(token: &str) -> String {
+    for suffix in ["ing", "ed", "s"] {
+        if let Some(stripped) = token.strip_suffix(suffix)
+            && stripped.len() >= 3
+        {
+            return stripped.to_string();
+        }
+    }
+    token.to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_similarity_identical_titles() {
+        assert_eq!(similarity("Login fails on Safari", "login fails on safari"), 1.0);
+    }
+
+    #[test]
+    fn test_similarity_ignores_punctuation_stop_words_and_plurals() {
+        assert_eq!(
+            similarity("Fix the crash when uploading files", "fix crash uploading file!"),
+            1.0
+        );
+    }
+
+    #[test]
+    fn test_similarity_partial_overlap() {
+        let score = similarity("API timeout on payments", "Payments API returns 500");
+        assert!(score > 0.3 && score < DEFAULT_THRESHOLD, "score was {}", score);
+    }
+
+    #[test]
+    fn test_similarity_unrelated() {
+        assert_eq!(similarity("Dark mode toggle", "Billing export CSV"), 0.0);
+        assert_eq!(similarity("", "Anything"), 0.0);
+    }
+
+    #[test]
+    fn test_open_issues_filter_excludes_closed_states() {
+        let filter = open_issues_filter("team-1");
+        assert_eq!(filter["team"]["id"]["eq"], "team-1");
+        assert_eq!(filter["state"]["type"]["nin"], json!(["completed", "canceled"]));
+    }
+}
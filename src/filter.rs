@@ -0,0 +1,76 @@
+//! Builds Linear `IssueFilter` objects from the shared issue filter flags.
+
+use crate::cli::IssueFilterArgs;
+use crate::resolve;
+use serde_json::{Map, Value, json};
+
+const STATE_TYPES: &[&str] = &["triage", "backlog", "unstarted", "started", "completed", "canceled"];
+
+/// A state type such as `started` matches every state of that type; anything
+/// else is matched against state names.
+pub fn state_filter(value: &str) -> Value {
+    if STATE_TYPES.iter().any(|state_type| state_type.eq_ignore_ascii_case(value)) {
+        json!({ "type": { "eq": value.to_ascii_lowercase() } })
+    } else {
+        json!({ "name": { "eqIgnoreCase": value } })
+    }
+}
+
+/// Every label must be present on the issue.
+pub fn labels_filter(names: &[String]) -> Option<Value> {
+    match names {
+        [] => None,
+        [name] => Some(json!({ "some": { "name": { "eqIgnoreCase": name } } })),
+        names => Some(json!({
+            "and": names
+                .iter()
+                .map(|name| json!({ "some": { "name": { "eqIgnoreCase": name } } }))
+                .collect::<Vec<_>>()
+        })),
+    }
+}
+
+pub async fn issue_filter(args: &IssueFilterArgs) -> Result<Value, Box<dyn std::error::Error>> {
+    let mut filter = Map::new();
+    if let Some(team) = &args.team {
+        let team_id = resolve::resolve_team(team).await?;
+        filter.insert("team".into(), json!({ "id": { "eq": team_id } }));
+    }
+    if let Some(state) = &args.state {
+        filter.insert("state".into(), state_filter(state));
+    }
+    if let Some(assignee) = &args.assignee {
+        let user_id = resolve::resolve_user(assignee).await?;
+        filter.insert("assignee".into(), json!({ "id": { "eq": user_id } }));
+    }
+    if let Some(project) = &args.project {
+        let project_id = resolve::resolve_project(project).await?;
+        filter.insert("project".into(), json!({ "id": { "eq": project_id } }));
+    }
+    if let Some(labels) = labels_filter(&args.labels) {
+        filter.insert("labels".into(), labels);
+    }
+    Ok(Value::Object(filter))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_state_filter_by_type_or_name() {
+        assert_eq!(state_filter("Started"), json!({ "type": { "eq": "started" } }));
+        assert_eq!(state_filter("In Review"), json!({ "name": { "eqIgnoreCase": "In Review" } }));
+    }
+
+    #[test]
+    fn test_labels_filter() {
+        assert_eq!(labels_filter(&[]), None);
+        assert_eq!(
+            labels_filter(&["bug".to_string()]),
+            Some(json!({ "some": { "name": { "eqIgnoreCase": "bug" } } }))
+        );
+        let filter = labels_filter(&["bug".to_string(), "api".to_string()]).unwrap();
+        assert_eq!(filter["and"].as_array().unwrap().len(), 2);
+    }
+}
@@ -0,0 +1,237 @@
+//! Minimal YAML front matter support for issue markdown files.
+//!
+//! Only the flat subset needed for tickets is understood: `key: value` pairs,
+//! inline lists (`[a, b]`) and block lists (`- item`). Unknown keys are kept
+//! so that documents can carry their own metadata.
+
+const DELIMITER: &str = "---";
+
+#[derive(Debug, Default, PartialEq)]
+pub struct FrontMatter {
+    pub fields: Vec<(String, FieldValue)>,
+}
+
+#[derive(Debug, PartialEq)]
+pub enum FieldValue {
+    Scalar(String),
+    List(Vec<String>),
+}
+
+#[derive(Debug, PartialEq)]
+pub struct MarkdownDocument {
+    pub front_matter: FrontMatter,
+    pub body: String,
+}
+
+impl FrontMatter {
+    pub fn get(&self, key: &str) -> Option<&str> {
+        self.fields.iter().find(|(k, _)| k == key).and_then(|(_, v)| match v {
+            FieldValue::Scalar(s) if !s.is_empty() => Some(s.as_str()),
+            _ => None,
+        })
+    }
+
+    pub fn get_list(&self, key: &str) -> Vec<String> {
+        match self.fields.iter().find(|(k, _)| k == key) {
+            Some((_, FieldValue::List(items))) => items.clone(),
+            Some((_, FieldValue::Scalar(s))) if !s.is_empty() => split_inline_list(s),
+            _ => Vec::new(),
+        }
+    }
+}
+
+/// Splits a markdown file into its front matter and body.
+/// Files without a leading `---` block have empty front matter.
+pub fn parse(content: &str) -> Result<MarkdownDocument, String> {
+    let Some((block, body)) = split(content) else {
+        return Ok(MarkdownDocument {
+            front_matter: FrontMatter::default(),
+            body: content.to_string(),
+        });
+    };
+
+    let mut fields: Vec<(String, FieldValue)> = Vec::new();
+    for (index, line) in block.lines().enumerate() {
+        let trimmed = line.trim();
+        if trimmed.is_empty() || trimmed.starts_with('#') {
+            continue;
+        }
+
+        if let Some(item) = trimmed.strip_prefix("- ") {
+            match fields.last_mut() {
+                Some((_, FieldValue::List(items))) => items.push(unquote(item.trim())),
+                Some((_, value @ FieldValue::Scalar(_))) if *value == FieldValue::Scalar(String::new()) => {
+                    *value = FieldValue::List(vec![unquote(item.trim())]);
+                }
+                _ => return Err(format!("front matter line {}: list item without a key", index + 2)),
+            }
+            continue;
+        }
+
+        let Some((key, value)) = trimmed.split_once(':') else {
+            return Err(format!("front matter line {}: expected `key: value`", index + 2));
+        };
+        let key = key.trim().to_string();
+        let value = value.trim();
+        let value = if value.starts_with('[') && value.ends_with(']') {
+            FieldValue::List(split_inline_list(value))
+        } else {
+            FieldValue::Scalar(unquote(value))
+        };
+        fields.push((key, value));
+    }
+
+    Ok(MarkdownDocument {
+        front_matter: FrontMatter { fields },
+        body: body.to_string(),
+    })
+}
+
+/// Sets a scalar key in the front matter, replacing an existing entry or
+/// appending a new one. A front matter block is created if the file has none.
+/// The file's line ending (`\n` or `\r\n`) is kept.
+pub fn set_field(content: &str, key: &str, value: &str) -> String {
+    let entry = format!("{}: {}", key, value);
+    let newline = if content.contains("\r\n") { "\r\n" } else { "\n" };
+
+    let Some((block, body)) = split(content) else {
+        return format!("{1}{0}{2}{0}{1}{0}{3}", newline, DELIMITER, entry, content);
+    };
+
+    let mut replaced = false;
+    let mut lines: Vec<String> = block
+        .lines()
+        .map(|line| {
+            let is_key = line
+                .split_once(':')
+                .is_some_and(|(k, _)| !line.starts_with(' ') && k.trim() == key);
+            if is_key && !replaced {
+                replaced = true;
+                entry.clone()
+            } else {
+                line.to_string()
+            }
+        })
+        .collect();
+    if !replaced {
+        lines.push(entry);
+    }
+
+    format!("{1}{0}{2}{0}{1}{0}{3}", newline, DELIMITER, lines.join(newline), body)
+}
+
+fn split(content: &str) -> Option<(&str, &str)> {
+    let rest = content
+        .strip_prefix("---\n")
+        .or_else(|| content.strip_prefix("---\r\n"))?;
+
+    let mut offset = 0;
+    for line in rest.split_inclusive('\n') {
+        if line.trim_end() == DELIMITER {
+            let block = rest[..offset].trim_end_matches(['\n', '\r']);
+            return Some((block, &rest[offset + line.len()..]));
+        }
+        offset += line.len();
+    }
+    None
+}
+
+fn split_inline_list(value: &str) -> Vec<String> {
+    value
+        .trim_start_matches('[')
+        .trim_end_matches(']')
+        .split(',')
+        .map(|item| unquote(item.trim()))
+        .filter(|item| !item.is_empty())
+        .collect()
+}
+
+fn unquote(value: &str) -> String {
+    let quoted = value.len() >= 2
+        && ((value.starts_with('"') && value.ends_with('"'))
+            || (value.starts_with('\'') && value.ends_with('\'')));
+    if quoted {
+        value[1..value.len() - 1].to_string()
+    } else {
+        value.to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_front_matter_and_body() {
+        let content = "---\nteam: ENG\ntitle: \"Fix login\"\npriority: high\n---\nThe body.\n";
+        let doc = parse(content).unwrap();
+        assert_eq!(doc.front_matter.get("team"), Some("ENG"));
+        assert_eq!(doc.front_matter.get("title"), Some("Fix login"));
+        assert_eq!(doc.front_matter.get("priority"), Some("high"));
+        assert_eq!(doc.body, "The body.\n");
+    }
+
+    #[test]
+    fn test_parse_inline_and_block_lists() {
+        let content = "---\nlabels: [bug, 'needs triage']\nsubscribers:\n  - alice\n  - bob\n---\n";
+        let doc = parse(content).unwrap();
+        assert_eq!(doc.front_matter.get_list("labels"), vec!["bug", "needs triage"]);
+        assert_eq!(doc.front_matter.get_list("subscribers"), vec!["alice", "bob"]);
+    }
+
+    #[test]
+    fn test_parse_without_front_matter() {
+        let doc = parse("# Just markdown\n").unwrap();
+        assert!(doc.front_matter.fields.is_empty());
+        assert_eq!(doc.body, "# Just markdown\n");
+    }
+
+    #[test]
+    fn test_parse_rejects_malformed_line() {
+        let result = parse("---\nnot a pair\n---\n");
+        assert!(result.is_err());
+        assert!(result.unwrap_err().contains("line 2"));
+    }
+
+    #[test]
+    fn test_get_ignores_empty_values() {
+        let doc = parse("---\nproject:\n---\n").unwrap();
+        assert_eq!(doc.front_matter.get("project"), None);
+    }
+
+    #[test]
+    fn test_set_field_appends_key() {
+        let content = "---\nteam: ENG\n---\nBody\n";
+        let updated = set_field(content, "id", "ENG-42");
+        assert_eq!(updated, "---\nteam: ENG\nid: ENG-42\n---\nBody\n");
+    }
+
+    #[test]
+    fn test_set_field_replaces_existing_key() {
+        let content = "---\nid: ENG-1\nteam: ENG\n---\nBody\n";
+        let updated = set_field(content, "id", "ENG-42");
+        assert_eq!(updated, "---\nid: ENG-42\nteam: ENG\n---\nBody\n");
+    }
+
+    #[test]
+    fn test_set_field_keeps_crlf_line_endings() {
+        let content = "---\r\ntitle: Fix login\r\nlabels: [bug]\r\n---\r\nBody\r\n";
+        let updated = set_field(content, "identifier", "ENG-1");
+        assert_eq!(
+            updated,
+            "---\r\ntitle: Fix login\r\nlabels: [bug]\r\nidentifier: ENG-1\r\n---\r\nBody\r\n"
+        );
+        assert!(!updated.replace("\r\n", "").contains('\n'));
+        assert_eq!(parse(&updated).unwrap().front_matter.get("identifier"), Some("ENG-1"));
+
+        let created = set_field("Body\r\n", "identifier", "ENG-2");
+        assert_eq!(created, "---\r\nidentifier: ENG-2\r\n---\r\nBody\r\n");
+    }
+
+    #[test]
+    fn test_set_field_creates_front_matter() {
+        let updated = set_field("Body\n", "id", "ENG-42");
+        assert_eq!(updated, "---\nid: ENG-42\n---\nBody\n");
+        assert_eq!(parse(&updated).unwrap().front_matter.get("id"), Some("ENG-42"));
+    }
+}
@@ -0,0 +1,225 @@
+use crate::api;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+/// Runs git with the given arguments and returns its trimmed stdout.
+pub fn run(args: &[&str]) -> Result<String, Box<dyn std::error::Error>> {
+    run_in(Path::new("."), args)
+}
+
+/// Like [`run`], but from inside `dir`.
+pub fn run_in(dir: &Path, args: &[&str]) -> Result<String, Box<dyn std::error::Error>> {
+    let output = Command::new("git")
+        .arg("-C")
+        .arg(dir)
+        .args(args)
+        .output()
+        .map_err(|e| format!("failed to run git: {}", e))?;
+
+    if !output.status.success() {
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        return Err(format!("git {} failed: {}", args.join(" "), stderr.trim()).into());
+    }
+    Ok(String::from_utf8_lossy(&output.stdout).trim().to_string())
+}
+
+/// Where the branch to switch to already exists, if anywhere.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum BranchSource {
+    Local,
+    /// Only on a remote, e.g. pushed by a teammate.
+    Remote,
+    New,
+}
+
+pub fn branch_source(name: &str) -> BranchSource {
+    if run(&["rev-parse", "--verify", "--quiet", &format!("refs/heads/{}", name)]).is_ok() {
+        return BranchSource::Local;
+    }
+    let remote = run(&["for-each-ref", "--format=%(refname)", &format!("refs/remotes/*/{}", name)]);
+    match remote {
+        Ok(refs) if !refs.is_empty() => BranchSource::Remote,
+        _ => BranchSource::New,
+    }
+}
+
+/// `git switch <name>` also creates a local branch tracking the remote one,
+/// so `-c` is only used when there's nothing to start from.
+fn switch_args(name: &str, source: BranchSource) -> Vec<&str> {
+    match source {
+        BranchSource::Local | BranchSource::Remote => vec!["switch", name],
+        BranchSource::New => vec!["switch", "-c", name],
+    }
+}
+
+/// Switches to `name`, creating the branch first if needed. In dry-run mode
+/// the git command is printed instead.
+pub fn switch_branch(name: &str) -> Result<BranchSource, Box<dyn std::error::Error>> {
+    let source = branch_source(name);
+    let args = switch_args(name, source);
+
+    if api::is_dry_run() {
+        println!("git {}", args.join(" "));
+    } else {
+        run(&args)?;
+    }
+    Ok(source)
+}
+
+pub fn current_branch() -> Result<String, Box<dyn std::error::Error>> {
+    run(&["symbolic-ref", "--short", "HEAD"])
+}
+
+/// Path of a hook in the repository, honouring `core.hooksPath`.
+pub fn hook_path(name: &str) -> Result<PathBuf, Box<dyn std::error::Error>> {
+    Ok(PathBuf::from(run(&["rev-parse", "--git-path", &format!("hooks/{}", name)])?))
+}
+
+/// `https://host/owner/repo` for the `origin` remote, from either an SSH or HTTPS URL.
+pub fn web_url(remote: &str) -> Option<String> {
+    let remote = remote.trim().trim_end_matches('/').trim_end_matches(".git");
+    if let Some(rest) = remote.strip_prefix("git@") {
+        let (host, path) = rest.split_once(':')?;
+        return Some(format!("https://{}/{}", host, path));
+    }
+    if let Some(rest) = remote.strip_prefix("ssh://") {
+        let rest = rest.split_once('@').map_or(rest, |(_, rest)| rest);
+        let (host, path) = rest.split_once('/')?;
+        let host = host.split(':').next()?;
+        return Some(format!("https://{}/{}", host, path));
+    }
+    if remote.starts_with("https://") || remote.starts_with("http://") {
+        let (scheme, rest) = remote.split_once("://")?;
+        let rest = rest.split_once('@').map_or(rest, |(_, rest)| rest);
+        return Some(format!("{}://{}", scheme, rest));
+    }
+    None
+}
+
+/// Finds an issue identifier such as `ENG-123` in a branch name like
+/// `kxzk/eng-123-fix-api`. The last path segment is searched first.
+pub fn issue_identifier(branch: &str) -> Option<String> {
+    issue_identifiers(branch).into_iter().next()
+}
+
+/// Every `key-number` pair in the branch name that could be an issue
+/// identifier, in the order [`issue_identifier`] prefers them. Words such as
+/// `fix-404` look the same, so callers that act on the result should check
+/// the key against the workspace's teams.
+pub fn issue_identifiers(branch: &str) -> Vec<String> {
+    branch
+        .rsplit('/')
+        .flat_map(|segment| segment.split('_'))
+        .flat_map(|part| {
+            let words: Vec<&str> = part.split('-').collect();
+            (0..words.len().saturating_sub(1))
+                .map(move |i| (words[i], words[i + 1]))
+                .filter(|(key, number)| is_team_key(key) && is_issue_number(number))
+                .map(|(key, number)| format!("{}-{}", key.to_ascii_uppercase(), number))
+                .collect::<Vec<_>>()
+        })
+        .collect()
+}
+
+fn is_team_key(word: &str) -> bool {
+    !word.is_empty() && word.len() <= 7 && word.chars().all(|c| c.is_ascii_alphabetic())
+}
+
+fn is_issue_number(word: &str) -> bool {
+    !word.is_empty() && !word.starts_with('0') && word.chars().all(|c| c.is_ascii_digit())
+}
+
+/// Branch name in Linear's default `identifier-title-slug` format, used when
+/// the API doesn't return a `branchName`.
+pub fn default_branch_name(identifier: &str, title: &str) -> String {
+    let mut slug = String::new();
+    for word in title
+        .to_lowercase()
+        .split(|c: char| !c.is_ascii_alphanumeric())
+        .filter(|word| !word.is_empty())
+    {
+        if slug.len() + word.len() + 1 > 50 {
+            break;
+        }
+        if !slug.is_empty() {
+            slug.push('-');
+        }
+        slug.push_str(word);
+    }
+
+    let identifier = identifier.to_lowercase();
+    if slug.is_empty() {
+        identifier
+    } else {
+        format!("{}-{}", identifier, slug)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_issue_identifier_from_branch() {
+        assert_eq!(issue_identifier("kxzk/eng-123-fix-api"), Some("ENG-123".to_string()));
+        assert_eq!(issue_identifier("ENG-42"), Some("ENG-42".to_string()));
+        assert_eq!(issue_identifier("feature/web-7_login"), Some("WEB-7".to_string()));
+        assert_eq!(issue_identifier("eng-5/wip-notes"), Some("ENG-5".to_string()));
+    }
+
+    #[test]
+    fn test_issue_identifiers_lists_every_candidate() {
+        assert_eq!(issue_identifiers("hotfix/fix-404-eng-12"), vec!["FIX-404", "ENG-12"]);
+        assert_eq!(issue_identifiers("hotfix/release-2"), vec!["RELEASE-2"]);
+        assert!(issue_identifiers("main").is_empty());
+    }
+
+    #[test]
+    fn test_issue_identifier_not_found() {
+        assert_eq!(issue_identifier("main"), None);
+        assert_eq!(issue_identifier("release/v1-2"), None);
+        assert_eq!(issue_identifier("kxzk/fix-api-timeouts"), None);
+        assert_eq!(issue_identifier("eng-0123"), None);
+    }
+
+    #[test]
+    fn test_switch_args_track_remote_branches() {
+        assert_eq!(switch_args("eng-1-fix", BranchSource::Local), vec!["switch", "eng-1-fix"]);
+        assert_eq!(switch_args("eng-1-fix", BranchSource::Remote), vec!["switch", "eng-1-fix"]);
+        assert_eq!(switch_args("eng-1-fix", BranchSource::New), vec!["switch", "-c", "eng-1-fix"]);
+    }
+
+    #[test]
+    fn test_web_url_from_remotes() {
+        let expected = Some("https://github.com/kxzk/linearite".to_string());
+        assert_eq!(web_url("git@github.com:kxzk/linearite.git"), expected);
+        assert_eq!(web_url("https://github.com/kxzk/linearite.git\n"), expected);
+        assert_eq!(web_url("https://token@github.com/kxzk/linearite"), expected);
+        assert_eq!(web_url("ssh://git@github.com:22/kxzk/linearite.git"), expected);
+        assert_eq!(web_url("/srv/git/linearite.git"), None);
+    }
+
+    #[test]
+    fn test_default_branch_name() {
+        assert_eq!(
+            default_branch_name("ENG-123", "Fix API: timeouts on /payments"),
+            "eng-123-fix-api-timeouts-on-payments"
+        );
+    }
+
+    #[test]
+    fn test_default_branch_name_truncates_on_word_boundary() {
+        let name = default_branch_name(
+            "ENG-1",
+            "A very long issue title that keeps going well past the point where branch names stay readable",
+        );
+        assert!(name.len() <= "eng-1-".len() + 50);
+        assert!(!name.ends_with('-'));
+        assert!(name.starts_with("eng-1-a-very-long-issue-title"));
+    }
+
+    #[test]
+    fn test_default_branch_name_without_title_words() {
+        assert_eq!(default_branch_name("ENG-9", "!!!"), "eng-9");
+    }
+}
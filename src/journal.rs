@@ -0,0 +1,181 @@
+//! Local journal of idempotency keys used by `create --idempotency-key`.
+//!
+//! Each line of `$XDG_STATE_HOME/linearite/idempotency.jsonl` (falling back to
+//! `~/.local/state`) records a key and the issue it produced. The file is only
+//! ever appended to, never rewritten, so concurrent `batch create` workers and
+//! separate processes can't lose each other's entries. Entries older than
+//! [`RETENTION_SECS`] are ignored when the journal is read.
+
+use crate::types::Issue;
+use serde::{Deserialize, Serialize};
+use std::env;
+use std::fs::{self, OpenOptions};
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+const RETENTION_SECS: u64 = 30 * 24 * 60 * 60;
+
+#[derive(Deserialize, Serialize)]
+pub struct Entry {
+    pub key: String,
+    pub recorded_at: u64,
+    pub issue: Issue,
+}
+
+pub fn state_dir() -> Option<PathBuf> {
+    match env::var_os("XDG_STATE_HOME") {
+        Some(dir) if !dir.is_empty() => Some(PathBuf::from(dir).join("linearite")),
+        _ => env::var_os("HOME").map(|home| PathBuf::from(home).join(".local/state/linearite")),
+    }
+}
+
+fn journal_path() -> Option<PathBuf> {
+    state_dir().map(|dir| dir.join("idempotency.jsonl"))
+}
+
+fn now() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|elapsed| elapsed.as_secs())
+        .unwrap_or(0)
+}
+
+fn read_entries() -> Vec<Entry> {
+    journal_path()
+        .and_then(|path| fs::read_to_string(path).ok())
+        .map(|content| live_entries(parse_entries(&content), now()))
+        .unwrap_or_default()
+}
+
+fn live_entries(entries: Vec<Entry>, now: u64) -> Vec<Entry> {
+    entries
+        .into_iter()
+        .filter(|entry| now.saturating_sub(entry.recorded_at) <= RETENTION_SECS)
+        .collect()
+}
+
+fn parse_entries(content: &str) -> Vec<Entry> {
+    content
+        .lines()
+        .filter_map(|line| serde_json::from_str(line).ok())
+        .collect()
+}
+
+pub fn lookup(key: &str) -> Option<Issue> {
+    read_entries()
+        .into_iter()
+        .rev()
+        .find(|entry| entry.key == key)
+        .map(|entry| entry.issue)
+}
+
+pub fn record(key: &str, issue: Issue) -> Result<(), Box<dyn std::error::Error>> {
+    let path = journal_path().ok_or("cannot locate state directory: HOME is not set")?;
+    if let Some(dir) = path.parent() {
+        fs::create_dir_all(dir)?;
+    }
+
+    let entry = Entry {
+        key: key.to_string(),
+        recorded_at: now(),
+        issue,
+    };
+    append(&path, &entry)
+}
+
+/// Appends the entry as one line with a single write.
+fn append(path: &Path, entry: &Entry) -> Result<(), Box<dyn std::error::Error>> {
+    let line = serde_json::to_string(entry)? + "\n";
+    let mut file = OpenOptions::new().create(true).append(true).open(path)?;
+    file.write_all(line.as_bytes())?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_entries_skips_invalid_lines() {
+        let content = concat!(
+            r#"{"key":"k1","recorded_at":1,"issue":{"id":"i1","identifier":"ENG-1","title":"One","url":"u1","branchName":null}}"#,
+            "\nnot json\n",
+            r#"{"key":"k2","recorded_at":2,"issue":{"id":"i2","identifier":"ENG-2","title":"Two","url":"u2","branchName":"eng-2"}}"#,
+            "\n"
+        );
+        let entries = parse_entries(content);
+        assert_eq!(entries.len(), 2);
+        assert_eq!(entries[0].key, "k1");
+        assert_eq!(entries[1].issue.identifier, Some("ENG-2".to_string()));
+        assert_eq!(entries[1].issue.branch_name, Some("eng-2".to_string()));
+    }
+
+    fn entry(key: &str, recorded_at: u64) -> Entry {
+        Entry {
+            key: key.to_string(),
+            recorded_at,
+            issue: Issue {
+                id: format!("issue-{}", key),
+                identifier: None,
+                title: "Title".to_string(),
+                url: "https://linear.app/issue".to_string(),
+                branch_name: None,
+            },
+        }
+    }
+
+    fn temp_journal(name: &str) -> PathBuf {
+        let path = env::temp_dir().join(format!("linearite-{}-{}.jsonl", name, std::process::id()));
+        let _ = fs::remove_file(&path);
+        path
+    }
+
+    #[test]
+    fn test_concurrent_appends_keep_every_entry() {
+        let path = temp_journal("appends");
+        std::thread::scope(|scope| {
+            for worker in 0..8 {
+                let path = &path;
+                scope.spawn(move || {
+                    for n in 0..25 {
+                        append(path, &entry(&format!("{}-{}", worker, n), 1)).unwrap();
+                    }
+                });
+            }
+        });
+        let entries = parse_entries(&fs::read_to_string(&path).unwrap());
+        fs::remove_file(&path).unwrap();
+        assert_eq!(entries.len(), 200);
+    }
+
+    #[test]
+    fn test_expired_entries_are_ignored() {
+        let now = 100 * RETENTION_SECS;
+        let entries = vec![entry("old", now - RETENTION_SECS - 1), entry("new", now - 1)];
+        let live = live_entries(entries, now);
+        assert_eq!(live.len(), 1);
+        assert_eq!(live[0].key, "new");
+    }
+
+    #[test]
+    fn test_entry_round_trip() {
+        let entry = Entry {
+            key: "deploy-42".to_string(),
+            recorded_at: 1_700_000_000,
+            issue: Issue {
+                id: "issue-1".to_string(),
+                identifier: Some("ENG-1".to_string()),
+                title: "Title".to_string(),
+                url: "https://linear.app/eng-1".to_string(),
+                branch_name: None,
+            },
+        };
+        let line = serde_json::to_string(&entry).unwrap();
+        let parsed = parse_entries(&line);
+        assert_eq!(parsed.len(), 1);
+        assert_eq!(parsed[0].key, "deploy-42");
+        assert_eq!(parsed[0].recorded_at, 1_700_000_000);
+        assert_eq!(parsed[0].issue.id, "issue-1");
+    }
+}
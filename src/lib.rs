@@ -1,5 +1,17 @@
 pub mod api;
+pub mod batch;
 pub mod cli;
 pub mod commands;
+pub mod config;
+pub mod dedupe;
+pub mod filter;
+pub mod frontmatter;
+pub mod git;
+pub mod journal;
+pub mod message;
+pub mod query;
+pub mod resolve;
+pub mod search;
+pub mod todos;
 pub mod types;
-
+pub mod upload;
@@ -1,24 +1,139 @@
 use clap::Parser;
-use linearite::cli::{Cli, Commands};
-use linearite::commands;
+use linearite::api;
+use linearite::cli::{
+    BatchCommands, BulkCommands, Cli, Commands, DocCommands, GitCommands, InitiativeCommands,
+    MilestoneCommands, ProjectCommands, TodosCommands,
+};
+use linearite::commands::{self, IssuesAction};
 
 #[tokio::main]
 async fn main() {
     let cli = Cli::parse();
+    api::set_dry_run(cli.dry_run);
 
     let result = match &cli.command {
-        Commands::Create { title, description, team_id, project_id } => {
-            commands::handle_create(title, description, team_id, project_id).await
+        Commands::Create(args) => {
+            commands::handle_create(args).await
+        }
+        Commands::View(args) => {
+            commands::handle_view(args).await
+        }
+        Commands::Comment(args) => {
+            commands::handle_comment(args).await
+        }
+        Commands::Close(args) => {
+            commands::handle_close(args).await
+        }
+        Commands::Checkout(args) => {
+            commands::handle_checkout(args).await
+        }
+        Commands::Git { command } => match command {
+            GitCommands::CommitMsg(args) => commands::handle_git_commit_msg(args).await,
+            GitCommands::PrBody(args) => commands::handle_git_pr_body(args).await,
+            GitCommands::InstallHook { force } => commands::handle_git_install_hook(*force),
+            GitCommands::PrepareCommitMsg { file, source, .. } => {
+                commands::handle_git_prepare_commit_msg(file, source.as_deref()).await
+            }
+        },
+        Commands::Whoami => {
+            commands::handle_whoami().await
+        }
+        Commands::My(args) => {
+            commands::handle_my(args).await
+        }
+        Commands::Update(args) => {
+            commands::handle_update(args).await
+        }
+        Commands::Archive(args) => {
+            commands::handle_issues_action(IssuesAction::Archive, args).await
+        }
+        Commands::Unarchive(args) => {
+            commands::handle_issues_action(IssuesAction::Unarchive, args).await
+        }
+        Commands::Delete(args) => {
+            commands::handle_issues_action(IssuesAction::Delete, args).await
+        }
+        Commands::Restore(args) => {
+            commands::handle_issues_action(IssuesAction::Restore, args).await
+        }
+        Commands::Attach(args) => {
+            commands::handle_attach(args).await
+        }
+        Commands::Milestone { command } => match command {
+            MilestoneCommands::List { project } => commands::handle_milestone_list(project).await,
+            MilestoneCommands::Create(args) => commands::handle_milestone_create(args).await,
+        },
+        Commands::Doc { command } => match command {
+            DocCommands::Create(args) => commands::handle_doc_create(args).await,
+            DocCommands::View { document } => commands::handle_doc_view(document).await,
+            DocCommands::Update(args) => commands::handle_doc_update(args).await,
+            DocCommands::List { project } => commands::handle_doc_list(project.as_deref()).await,
+            DocCommands::Export { document, output } => {
+                commands::handle_doc_export(document, output.as_deref()).await
+            }
+        },
+        Commands::Initiative { command } => match command {
+            InitiativeCommands::View { initiative } => commands::handle_initiative_view(initiative).await,
+            InitiativeCommands::AddProject { initiative, project } => {
+                commands::handle_initiative_add_project(initiative, project).await
+            }
+        },
+        Commands::Project { command } => match command {
+            ProjectCommands::Create(args) => commands::handle_project_create(args).await,
+            ProjectCommands::Update(args) => commands::handle_project_update(args).await,
+            ProjectCommands::Archive { project } => commands::handle_project_archive(project).await,
+            ProjectCommands::View { project } => commands::handle_project_view(project).await,
+            ProjectCommands::PostUpdate(args) => commands::handle_project_post_update(args).await,
+            ProjectCommands::Updates { project, limit } => {
+                commands::handle_project_updates(project, *limit).await
+            }
+        },
+        Commands::List(args) => {
+            commands::handle_list(args).await
+        }
+        Commands::Q(args) => {
+            commands::handle_saved_query(args).await
+        }
+        Commands::ViewList(args) => {
+            commands::handle_view_list(args).await
+        }
+        Commands::Search(args) => {
+            commands::handle_search(args).await
+        }
+        Commands::Todos { command } => match command {
+            TodosCommands::Scan(args) => commands::handle_todos_scan(args).await,
+            TodosCommands::Check { path } => commands::handle_todos_check(path).await,
+        },
+        Commands::Batch { command: BatchCommands::Create(args) } => {
+            commands::handle_batch_create(args).await
+        }
+        Commands::Bulk { command: BulkCommands::Update(args) } => {
+            commands::handle_bulk_update(args).await
         }
         Commands::ListTeams => {
             commands::handle_list_teams().await
         }
-        Commands::ListProjects => {
-            commands::handle_list_projects().await
+        Commands::ListProjects(args) => {
+            commands::handle_list_projects(args).await
+        }
+        Commands::ListStates { team } => {
+            commands::handle_list_states(team).await
+        }
+        Commands::ListInitiatives => {
+            commands::handle_list_initiatives().await
+        }
+        Commands::ListUsers => {
+            commands::handle_list_users().await
+        }
+        Commands::ListMembers { team } => {
+            commands::handle_list_members(team).await
         }
     };
 
     if let Err(e) = result {
+        if e.is::<api::DryRun>() {
+            return;
+        }
         eprintln!("Error: {}", e);
         std::process::exit(1);
     }
@@ -0,0 +1,203 @@
+//! Commit messages and PR descriptions that Linear's GitHub integration links
+//! back to the issue through magic words such as `Fixes ENG-123`.
+
+use crate::types::IssueDetail;
+use clap::ValueEnum;
+
+#[derive(Clone, Copy, ValueEnum)]
+pub enum MagicWord {
+    /// Closes the issue when merged
+    Fixes,
+    /// Closes the issue when merged
+    Closes,
+    /// Closes the issue when merged
+    Resolves,
+    /// Links without closing
+    Refs,
+    /// Links without closing
+    PartOf,
+    /// Links without closing
+    RelatedTo,
+}
+
+impl MagicWord {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            MagicWord::Fixes => "Fixes",
+            MagicWord::Closes => "Closes",
+            MagicWord::Resolves => "Resolves",
+            MagicWord::Refs => "Refs",
+            MagicWord::PartOf => "Part of",
+            MagicWord::RelatedTo => "Related to",
+        }
+    }
+}
+
+/// Conventional commit type inferred from the issue's labels.
+pub fn commit_type<'a>(labels: impl IntoIterator<Item = &'a str>) -> &'static str {
+    let mut commit_type = "feat";
+    for label in labels {
+        match label.to_ascii_lowercase().as_str() {
+            "bug" | "fix" | "bugfix" | "regression" => return "fix",
+            "docs" | "documentation" => commit_type = "docs",
+            "chore" | "maintenance" => commit_type = "chore",
+            "refactor" | "tech debt" | "tech-debt" => commit_type = "refactor",
+            "performance" | "perf" => commit_type = "perf",
+            "test" | "tests" | "testing" => commit_type = "test",
+            _ => {}
+        }
+    }
+    commit_type
+}
+
+fn label_names(issue: &IssueDetail) -> Vec<&str> {
+    issue
+        .labels
+        .as_ref()
+        .map(|labels| labels.nodes.iter().map(|label| label.name.as_str()).collect())
+        .unwrap_or_default()
+}
+
+pub fn commit_message(issue: &IssueDetail, commit_type_override: Option<&str>, magic_word: MagicWord) -> String {
+    let commit_type = commit_type_override.unwrap_or_else(|| commit_type(label_names(issue)));
+    format!(
+        "{}: {} ({})\n\n{} {}\n",
+        commit_type,
+        issue.title,
+        issue.identifier,
+        magic_word.as_str(),
+        issue.identifier
+    )
+}
+
+pub fn pr_body(issue: &IssueDetail, magic_word: MagicWord, include_description: bool) -> String {
+    let mut body = format!("[{}]({}): {}\n", issue.identifier, issue.url, issue.title);
+
+    if include_description
+        && let Some(description) = issue.description.as_deref().filter(|d| !d.trim().is_empty())
+    {
+        body += &format!("\n## Context\n\n{}\n", description.trim());
+    }
+
+    body += &format!("\n{} {}\n", magic_word.as_str(), issue.identifier);
+    body
+}
+
+pub fn trailer(identifier: &str, magic_word: MagicWord) -> String {
+    format!("{} {}", magic_word.as_str(), identifier)
+}
+
+/// Adds `trailer` after the message text, keeping git's `#` comment block last.
+pub fn append_trailer(message: &str, trailer: &str) -> String {
+    let (text, comments) = split_comments(message);
+    let text = text.trim_end();
+    let mut out = if text.is_empty() {
+        format!("\n\n{}\n", trailer)
+    } else {
+        format!("{}\n\n{}\n", text, trailer)
+    };
+    if !comments.is_empty() {
+        out += "\n";
+        out += comments;
+    }
+    out
+}
+
+/// Puts a generated message in front of an empty commit template.
+pub fn prepend_message(template: &str, message: &str) -> String {
+    let (text, comments) = split_comments(template);
+    if !text.trim().is_empty() {
+        return template.to_string();
+    }
+    if comments.is_empty() {
+        message.to_string()
+    } else {
+        format!("{}\n{}", message, comments)
+    }
+}
+
+fn split_comments(message: &str) -> (&str, &str) {
+    let mut offset = 0;
+    for line in message.split_inclusive('\n') {
+        if line.starts_with('#') {
+            return (&message[..offset], &message[offset..]);
+        }
+        offset += line.len();
+    }
+    (message, "")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn issue(labels: &[&str], description: Option<&str>) -> IssueDetail {
+        let labels: Vec<_> = labels.iter().map(|name| json!({ "name": name })).collect();
+        serde_json::from_value(json!({
+            "id": "issue-1",
+            "identifier": "ENG-123",
+            "title": "Login fails on Safari",
+            "url": "https://linear.app/team/issue/ENG-123",
+            "description": description,
+            "team": {"id": "team-1"},
+            "labels": {"nodes": labels}
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn test_commit_type_from_labels() {
+        assert_eq!(commit_type(["Bug", "docs"]), "fix");
+        assert_eq!(commit_type(["Documentation"]), "docs");
+        assert_eq!(commit_type(["frontend"]), "feat");
+        assert_eq!(commit_type([]), "feat");
+    }
+
+    #[test]
+    fn test_commit_message() {
+        assert_eq!(
+            commit_message(&issue(&["bug"], None), None, MagicWord::Fixes),
+            "fix: Login fails on Safari (ENG-123)\n\nFixes ENG-123\n"
+        );
+        assert_eq!(
+            commit_message(&issue(&["bug"], None), Some("chore"), MagicWord::PartOf),
+            "chore: Login fails on Safari (ENG-123)\n\nPart of ENG-123\n"
+        );
+    }
+
+    #[test]
+    fn test_pr_body() {
+        let body = pr_body(&issue(&[], Some("Steps to reproduce\n")), MagicWord::Closes, true);
+        assert_eq!(
+            body,
+            "[ENG-123](https://linear.app/team/issue/ENG-123): Login fails on Safari\n\n\
+             ## Context\n\nSteps to reproduce\n\n\
+             Closes ENG-123\n"
+        );
+
+        let body = pr_body(&issue(&[], Some("Steps")), MagicWord::Fixes, false);
+        assert!(!body.contains("Context"));
+        assert!(body.ends_with("\nFixes ENG-123\n"));
+    }
+
+    #[test]
+    fn test_append_trailer_before_git_comments() {
+        let message = "Fix the thing\n# Please enter the commit message\n# Lines starting with '#'\n";
+        assert_eq!(
+            append_trailer(message, "Fixes ENG-1"),
+            "Fix the thing\n\nFixes ENG-1\n\n# Please enter the commit message\n# Lines starting with '#'\n"
+        );
+        assert_eq!(append_trailer("Fix\n", "Fixes ENG-1"), "Fix\n\nFixes ENG-1\n");
+    }
+
+    #[test]
+    fn test_prepend_message_only_into_empty_template() {
+        let template = "\n# Please enter the commit message\n";
+        assert_eq!(
+            prepend_message(template, "fix: x (ENG-1)\n\nFixes ENG-1\n"),
+            "fix: x (ENG-1)\n\nFixes ENG-1\n\n# Please enter the commit message\n"
+        );
+        assert_eq!(prepend_message("Existing\n", "ignored"), "Existing\n");
+    }
+}
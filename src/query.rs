@@ -0,0 +1,397 @@
+//! The issue query language shared by `list` and `bulk update`. A query such as
+//! `assignee:me state:started priority:>=high label:bug updated:<7d project:"API v2"`
+//! compiles into a Linear `IssueFilter`; every term must match.
+
+use crate::filter;
+use crate::resolve;
+use serde_json::{Map, Value, json};
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Key {
+    Team,
+    State,
+    Assignee,
+    Creator,
+    Project,
+    Label,
+    Priority,
+    Title,
+    Created,
+    Updated,
+    Completed,
+    Due,
+}
+
+const KEYS: &[(&str, Key)] = &[
+    ("team", Key::Team),
+    ("state", Key::State),
+    ("assignee", Key::Assignee),
+    ("creator", Key::Creator),
+    ("project", Key::Project),
+    ("label", Key::Label),
+    ("priority", Key::Priority),
+    ("title", Key::Title),
+    ("created", Key::Created),
+    ("updated", Key::Updated),
+    ("completed", Key::Completed),
+    ("due", Key::Due),
+];
+
+impl Key {
+    fn name(self) -> &'static str {
+        KEYS.iter().find(|(_, key)| *key == self).map_or("", |(name, _)| name)
+    }
+
+    fn comparable(self) -> bool {
+        matches!(self, Key::Priority | Key::Created | Key::Updated | Key::Completed | Key::Due)
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Op {
+    Eq,
+    Lt,
+    Lte,
+    Gt,
+    Gte,
+}
+
+/// One `key:value` term. Comma-separated values match any of them.
+#[derive(Debug, PartialEq)]
+pub struct Term {
+    /// Byte offset of the term in the query, for error messages.
+    pub start: usize,
+    pub key: Key,
+    pub op: Op,
+    pub values: Vec<String>,
+}
+
+pub fn parse(query: &str) -> Result<Vec<Term>, String> {
+    split_terms(query)?
+        .into_iter()
+        .map(|(start, text)| parse_term(start, text).map_err(|e| error_at(query, start, &e)))
+        .collect()
+}
+
+/// Compiles a query into an `IssueFilter`, resolving names such as teams,
+/// users and projects to ids.
+pub async fn compile(query: &str) -> Result<Value, Box<dyn std::error::Error>> {
+    let mut filter = Map::new();
+    let mut repeated = Vec::new();
+    for term in parse(query)? {
+        let (field, value) = match static_filter(&term) {
+            Some(compiled) => compiled.map_err(Into::into),
+            None => resolved_filter(&term).await,
+        }
+        .map_err(|e| error_at(query, term.start, &e.to_string()))?;
+
+        // A key used twice, e.g. two labels, must match both times.
+        if filter.contains_key(field) {
+            repeated.push(json!({ field: value }));
+        } else {
+            filter.insert(field.into(), value);
+        }
+    }
+    if !repeated.is_empty() {
+        filter.insert("and".into(), Value::Array(repeated));
+    }
+    Ok(Value::Object(filter))
+}
+
+/// Points at the term that failed:
+///
+/// ```text
+/// unknown priority: hgh
+///   state:started priority:>=hgh
+///                 ^
+/// ```
+fn error_at(query: &str, start: usize, message: &str) -> String {
+    let column = query[..start].chars().count();
+    format!("{}\n  {}\n  {}^", message, query, " ".repeat(column))
+}
+
+/// Splits on whitespace outside double quotes, keeping each term's offset.
+fn split_terms(query: &str) -> Result<Vec<(usize, &str)>, String> {
+    let mut terms = Vec::new();
+    let mut start = None;
+    let mut quote = None;
+    for (index, c) in query.char_indices() {
+        if c == '"' {
+            quote = match quote {
+                Some(_) => None,
+                None => Some(index),
+            };
+        }
+        if c.is_whitespace() && quote.is_none() {
+            if let Some(start) = start.take() {
+                terms.push((start, &query[start..index]));
+            }
+        } else if start.is_none() {
+            start = Some(index);
+        }
+    }
+    if let Some(quote) = quote {
+        return Err(error_at(query, quote, "unterminated quote"));
+    }
+    if let Some(start) = start {
+        terms.push((start, &query[start..]));
+    }
+    Ok(terms)
+}
+
+fn parse_term(start: usize, text: &str) -> Result<Term, String> {
+    let (name, value) = text
+        .split_once(':')
+        .ok_or_else(|| format!("expected key:value, e.g. state:started (got {})", text))?;
+    let key = KEYS
+        .iter()
+        .find(|(known, _)| known.eq_ignore_ascii_case(name))
+        .map(|(_, key)| *key)
+        .ok_or_else(|| {
+            let names: Vec<_> = KEYS.iter().map(|(name, _)| *name).collect();
+            format!("unknown key: {} (expected one of {})", name, names.join(", "))
+        })?;
+
+    let (op, value) = [(">=", Op::Gte), ("<=", Op::Lte), (">", Op::Gt), ("<", Op::Lt), ("=", Op::Eq)]
+        .iter()
+        .find_map(|(prefix, op)| value.strip_prefix(prefix).map(|rest| (*op, rest)))
+        .unwrap_or((Op::Eq, value));
+    if op != Op::Eq && !key.comparable() {
+        return Err(format!("{} can't be compared with <, >, <= or >=", key.name()));
+    }
+
+    let values = split_values(value);
+    if values.is_empty() {
+        return Err(format!("missing value for {}", key.name()));
+    }
+    if op != Op::Eq && values.len() > 1 {
+        return Err(format!("{} comparisons take a single value", key.name()));
+    }
+    Ok(Term { start, key, op, values })
+}
+
+/// Splits on commas outside double quotes and drops the quotes.
+fn split_values(value: &str) -> Vec<String> {
+    let mut values = Vec::new();
+    let mut current = String::new();
+    let mut in_quotes = false;
+    for c in value.chars() {
+        match c {
+            '"' => in_quotes = !in_quotes,
+            ',' if !in_quotes => values.push(std::mem::take(&mut current)),
+            c => current.push(c),
+        }
+    }
+    values.push(current);
+    values.retain(|value| !value.trim().is_empty());
+    values
+}
+
+/// Matches any of the filters.
+fn any_of(mut filters: Vec<Value>) -> Value {
+    if filters.len() == 1 {
+        filters.remove(0)
+    } else {
+        json!({ "or": filters })
+    }
+}
+
+/// Terms that compile without looking anything up.
+fn static_filter(term: &Term) -> Option<Result<(&'static str, Value), String>> {
+    let values = &term.values;
+    let compiled = match term.key {
+        Key::State => Ok(("state", any_of(values.iter().map(|v| filter::state_filter(v)).collect()))),
+        Key::Label => Ok((
+            "labels",
+            json!({ "some": any_of(values.iter().map(|v| json!({ "name": { "eqIgnoreCase": v } })).collect()) }),
+        )),
+        Key::Title => Ok((
+            "title",
+            any_of(values.iter().map(|v| json!({ "containsIgnoreCase": v })).collect()),
+        )),
+        Key::Priority => priority_filter(term.op, values).map(|filter| ("priority", filter)),
+        Key::Created => date_filter(term.op, &values[0], false).map(|filter| ("createdAt", filter)),
+        Key::Updated => date_filter(term.op, &values[0], false).map(|filter| ("updatedAt", filter)),
+        Key::Completed => date_filter(term.op, &values[0], false).map(|filter| ("completedAt", filter)),
+        Key::Due => date_filter(term.op, &values[0], true).map(|filter| ("dueDate", filter)),
+        Key::Team | Key::Assignee | Key::Creator | Key::Project => return None,
+    };
+    Some(compiled)
+}
+
+async fn resolved_filter(term: &Term) -> Result<(&'static str, Value), Box<dyn std::error::Error>> {
+    let mut filters = Vec::new();
+    for value in &term.values {
+        let none = value.eq_ignore_ascii_case("none");
+        filters.push(match term.key {
+            Key::Team => json!({ "id": { "eq": resolve::resolve_team(value).await? } }),
+            Key::Project if none => json!({ "null": true }),
+            Key::Project => json!({ "id": { "eq": resolve::resolve_project(value).await? } }),
+            _ if none => json!({ "null": true }),
+            _ if value.eq_ignore_ascii_case("me") => json!({ "isMe": { "eq": true } }),
+            _ => json!({ "id": { "eq": resolve::resolve_user(value).await? } }),
+        });
+    }
+    let field = match term.key {
+        Key::Team => "team",
+        Key::Project => "project",
+        Key::Creator => "creator",
+        _ => "assignee",
+    };
+    Ok((field, any_of(filters)))
+}
+
+/// Urgency ranks priorities for comparisons: `>=high` means high or urgent,
+/// and "no priority" ranks below low.
+fn urgency(priority: u8) -> u8 {
+    if priority == 0 { 0 } else { 5 - priority }
+}
+
+fn priority_filter(op: Op, values: &[String]) -> Result<Value, String> {
+    let priorities = values
+        .iter()
+        .map(|value| resolve::parse_priority(value).map_err(|_| format!("unknown priority: {}", value)))
+        .collect::<Result<Vec<_>, _>>()?;
+
+    let matching: Vec<u8> = if op == Op::Eq {
+        priorities
+    } else {
+        let rank = urgency(priorities[0]);
+        (0..=4)
+            .filter(|priority| match op {
+                Op::Lt => urgency(*priority) < rank,
+                Op::Lte => urgency(*priority) <= rank,
+                Op::Gt => urgency(*priority) > rank,
+                _ => urgency(*priority) >= rank,
+            })
+            .collect()
+    };
+    match matching.as_slice() {
+        [] => Err(format!("no priority is {}", values[0])),
+        [priority] => Ok(json!({ "eq": priority })),
+        priorities => Ok(json!({ "in": priorities })),
+    }
+}
+
+/// Dates are `YYYY-MM-DD` or relative, e.g. `7d`, `12h`, `2w`, `3m` or `1y`.
+/// Relative dates count back from now (`updated:<7d` is "within the last 7
+/// days"), except for due dates, which count forward (`due:<7d` is "due in
+/// the next 7 days").
+fn date_filter(op: Op, value: &str, future: bool) -> Result<Value, String> {
+    let comparator = |op| match op {
+        Op::Eq => "eq",
+        Op::Lt => "lt",
+        Op::Lte => "lte",
+        Op::Gt => "gt",
+        Op::Gte => "gte",
+    };
+
+    let Some(duration) = relative_duration(value) else {
+        let date = resolve::parse_date(value)
+            .map_err(|_| format!("invalid date: {} (expected YYYY-MM-DD or a duration like 7d)", value))?;
+        return Ok(json!({ comparator(op): date }));
+    };
+    if future {
+        return Ok(json!({ comparator(op): duration }));
+    }
+    let op = match op {
+        Op::Eq => return Err(format!("use < or > with a relative date, e.g. <{}", value)),
+        Op::Lt => Op::Gt,
+        Op::Lte => Op::Gte,
+        Op::Gt => Op::Lt,
+        Op::Gte => Op::Lte,
+    };
+    Ok(json!({ comparator(op): format!("-{}", duration) }))
+}
+
+/// `7d` → `P7D`, `12h` → `PT12H`: ISO 8601 durations, which Linear's date
+/// comparators read relative to now.
+fn relative_duration(value: &str) -> Option<String> {
+    let (index, unit) = value.char_indices().last()?;
+    let unit = unit.to_ascii_lowercase();
+    let amount = &value[..index];
+    if amount.is_empty() || !amount.chars().all(|c| c.is_ascii_digit()) {
+        return None;
+    }
+    match unit {
+        'h' => Some(format!("PT{}H", amount)),
+        'd' | 'w' | 'm' | 'y' => Some(format!("P{}{}", amount, unit.to_ascii_uppercase())),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_terms() {
+        let terms = parse(r#"assignee:me priority:>=high project:"API v2" label:bug,"needs triage""#).unwrap();
+        assert_eq!(terms.len(), 4);
+        assert_eq!(terms[0], Term { start: 0, key: Key::Assignee, op: Op::Eq, values: vec!["me".into()] });
+        assert_eq!((terms[1].key, terms[1].op), (Key::Priority, Op::Gte));
+        assert_eq!(terms[2].values, vec!["API v2"]);
+        assert_eq!(terms[3].values, vec!["bug", "needs triage"]);
+        assert!(parse("   ").unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_parse_errors_point_at_the_term() {
+        let error = parse("state:started prio:high").unwrap_err();
+        assert!(error.starts_with("unknown key: prio (expected one of team, state,"));
+        assert!(error.ends_with("\n  state:started prio:high\n                ^"));
+
+        assert!(parse("stale").unwrap_err().starts_with("expected key:value"));
+        assert!(parse("state:").unwrap_err().starts_with("missing value for state"));
+        assert!(parse("label:>bug").unwrap_err().starts_with("label can't be compared"));
+        assert!(parse("priority:>high,low").unwrap_err().starts_with("priority comparisons take a single value"));
+        assert!(parse(r#"project:"API v2"#).unwrap_err().ends_with("        ^"));
+    }
+
+    #[test]
+    fn test_static_filters() {
+        let compile = |query: &str| {
+            let term = parse(query).unwrap().remove(0);
+            static_filter(&term).unwrap()
+        };
+        assert_eq!(
+            compile(r#"state:started,"In Review""#).unwrap(),
+            (
+                "state",
+                json!({ "or": [{ "type": { "eq": "started" } }, { "name": { "eqIgnoreCase": "In Review" } }] })
+            )
+        );
+        assert_eq!(
+            compile("label:bug").unwrap(),
+            ("labels", json!({ "some": { "name": { "eqIgnoreCase": "bug" } } }))
+        );
+        assert_eq!(compile("title:crash").unwrap(), ("title", json!({ "containsIgnoreCase": "crash" })));
+        assert_eq!(compile("updated:<7d").unwrap(), ("updatedAt", json!({ "gt": "-P7D" })));
+        assert_eq!(compile("due:<=2w").unwrap(), ("dueDate", json!({ "lte": "P2W" })));
+        assert!(compile("priority:hgh").unwrap_err().contains("unknown priority: hgh"));
+    }
+
+    #[test]
+    fn test_priority_filter() {
+        let value = |v: &str| vec![v.to_string()];
+        assert_eq!(priority_filter(Op::Gte, &value("high")).unwrap(), json!({ "in": [1, 2] }));
+        assert_eq!(priority_filter(Op::Lt, &value("medium")).unwrap(), json!({ "in": [0, 4] }));
+        assert_eq!(priority_filter(Op::Eq, &value("urgent")).unwrap(), json!({ "eq": 1 }));
+        assert_eq!(
+            priority_filter(Op::Eq, &["low".to_string(), "none".to_string()]).unwrap(),
+            json!({ "in": [4, 0] })
+        );
+        assert!(priority_filter(Op::Gt, &value("urgent")).is_err());
+    }
+
+    #[test]
+    fn test_date_filter() {
+        assert_eq!(date_filter(Op::Gt, "30d", false).unwrap(), json!({ "lt": "-P30D" }));
+        assert_eq!(date_filter(Op::Lte, "12h", false).unwrap(), json!({ "gte": "-PT12H" }));
+        assert_eq!(date_filter(Op::Gte, "2024-01-01", false).unwrap(), json!({ "gte": "2024-01-01" }));
+        assert!(date_filter(Op::Eq, "7d", false).unwrap_err().contains("use < or >"));
+        assert!(date_filter(Op::Lt, "soon", false).unwrap_err().contains("invalid date: soon"));
+        assert!(date_filter(Op::Lt, "7é", false).unwrap_err().contains("invalid date: 7é"));
+        assert!(date_filter(Op::Lt, "2ü", true).is_err());
+    }
+}
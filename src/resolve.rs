@@ -0,0 +1,391 @@
+use crate::api;
+use crate::git;
+use crate::types::{
+    Initiative, InitiativesResponse, IssueLabel, IssueLabelsResponse, IssueLookupResponse, Project, ProjectMilestone,
+    ProjectMilestonesResponse, ProjectsResponse, Team, TeamStatesResponse, TeamsResponse, User,
+    UsersResponse, ViewerResponse, WorkflowState,
+};
+use serde_json::json;
+use tokio::sync::OnceCell;
+
+// Lookup tables are fetched at most once per process, so batch commands
+// resolving the same names for many rows only pay for a single request.
+static TEAMS: OnceCell<Vec<Team>> = OnceCell::const_new();
+static PROJECTS: OnceCell<Vec<Project>> = OnceCell::const_new();
+static INITIATIVES: OnceCell<Vec<Initiative>> = OnceCell::const_new();
+static LABELS: OnceCell<Vec<IssueLabel>> = OnceCell::const_new();
+static USERS: OnceCell<Vec<User>> = OnceCell::const_new();
+static VIEWER: OnceCell<User> = OnceCell::const_new();
+
+async fn teams() -> Result<&'static Vec<Team>, Box<dyn std::error::Error>> {
+    TEAMS
+        .get_or_try_init(|| async {
+            let data = api::query_linear::<TeamsResponse>(
+                "query Teams { teams { nodes { id name key } } }",
+                None,
+            )
+            .await?;
+            Ok(data.teams.nodes)
+        })
+        .await
+}
+
+async fn projects() -> Result<&'static Vec<Project>, Box<dyn std::error::Error>> {
+    PROJECTS
+        .get_or_try_init(|| async {
+            let data = api::query_linear::<ProjectsResponse>(
+                "query Projects { projects(first: 250) { nodes { id name } } }",
+                None,
+            )
+            .await?;
+            Ok(data.projects.nodes)
+        })
+        .await
+}
+
+pub async fn initiatives() -> Result<&'static Vec<Initiative>, Box<dyn std::error::Error>> {
+    INITIATIVES
+        .get_or_try_init(|| async {
+            let data = api::query_linear::<InitiativesResponse>(
+                "query Initiatives { initiatives(first: 250) { nodes { id name status targetDate owner { id name } url } } }",
+                None,
+            )
+            .await?;
+            Ok(data.initiatives.nodes)
+        })
+        .await
+}
+
+async fn labels() -> Result<&'static Vec<IssueLabel>, Box<dyn std::error::Error>> {
+    LABELS
+        .get_or_try_init(|| async {
+            let data = api::query_linear::<IssueLabelsResponse>(
+                "query IssueLabels { issueLabels(first: 250) { nodes { id name team { id } } } }",
+                None,
+            )
+            .await?;
+            Ok(data.issue_labels.nodes)
+        })
+        .await
+}
+
+async fn users() -> Result<&'static Vec<User>, Box<dyn std::error::Error>> {
+    USERS
+        .get_or_try_init(|| async {
+            let data = api::query_linear::<UsersResponse>(
+                "query Users { users(first: 250) { nodes { id name displayName email } } }",
+                None,
+            )
+            .await?;
+            Ok(data.users.nodes)
+        })
+        .await
+}
+
+pub async fn viewer() -> Result<&'static User, Box<dyn std::error::Error>> {
+    VIEWER
+        .get_or_try_init(|| async {
+            let data = api::query_linear::<ViewerResponse>(
+                "query Viewer { viewer { id name displayName email } }",
+                None,
+            )
+            .await?;
+            Ok(data.viewer)
+        })
+        .await
+}
+
+pub async fn resolve_team(value: &str) -> Result<String, Box<dyn std::error::Error>> {
+    teams()
+        .await?
+        .iter()
+        .find(|team| {
+            team.id == value
+                || team.name.eq_ignore_ascii_case(value)
+                || team.key.as_deref().is_some_and(|key| key.eq_ignore_ascii_case(value))
+        })
+        .map(|team| team.id.clone())
+        .ok_or_else(|| format!("team not found: {}", value).into())
+}
+
+pub async fn resolve_initiative(value: &str) -> Result<String, Box<dyn std::error::Error>> {
+    initiatives()
+        .await?
+        .iter()
+        .find(|initiative| initiative.id == value || initiative.name.eq_ignore_ascii_case(value))
+        .map(|initiative| initiative.id.clone())
+        .ok_or_else(|| format!("initiative not found: {}", value).into())
+}
+
+pub async fn resolve_project(value: &str) -> Result<String, Box<dyn std::error::Error>> {
+    projects()
+        .await?
+        .iter()
+        .find(|project| project.id == value || project.name.eq_ignore_ascii_case(value))
+        .map(|project| project.id.clone())
+        .ok_or_else(|| format!("project not found: {}", value).into())
+}
+
+/// Resolves label names to ids, considering workspace labels and labels of the given team.
+pub async fn resolve_labels(
+    team_id: &str,
+    names: &[String],
+) -> Result<Vec<String>, Box<dyn std::error::Error>> {
+    if names.is_empty() {
+        return Ok(Vec::new());
+    }
+
+    let labels: Vec<_> = labels()
+        .await?
+        .iter()
+        .filter(|label| label.team.as_ref().is_none_or(|team| team.id == team_id))
+        .collect();
+
+    names
+        .iter()
+        .map(|name| {
+            labels
+                .iter()
+                .find(|label| label.id == *name || label.name.eq_ignore_ascii_case(name))
+                .map(|label| label.id.clone())
+                .ok_or_else(|| format!("label not found: {}", name).into())
+        })
+        .collect()
+}
+
+/// Resolves `me`, a user id, name, display name or email to a user id.
+pub async fn resolve_user(value: &str) -> Result<String, Box<dyn std::error::Error>> {
+    if value.eq_ignore_ascii_case("me") {
+        return Ok(viewer().await?.id.clone());
+    }
+
+    users()
+        .await?
+        .iter()
+        .find(|user| {
+            user.id == value
+                || user.name.eq_ignore_ascii_case(value)
+                || user.display_name.as_deref().is_some_and(|n| n.eq_ignore_ascii_case(value))
+                || user.email.as_deref().is_some_and(|e| e.eq_ignore_ascii_case(value))
+        })
+        .map(|user| user.id.clone())
+        .ok_or_else(|| format!("user not found: {}", value).into())
+}
+
+/// Resolves an issue identifier such as `ENG-123` (or an id) to the issue id.
+pub async fn resolve_issue(value: &str) -> Result<String, Box<dyn std::error::Error>> {
+    let data = api::query_linear::<IssueLookupResponse>(
+        "query Issue($id: String!) { issue(id: $id) { id identifier } }",
+        Some(json!({ "id": value })),
+    )
+    .await
+    .map_err(|e| format!("issue not found: {} ({})", value, e))?;
+
+    Ok(data.issue.id)
+}
+
+pub async fn project_milestones(
+    project_id: &str,
+) -> Result<Vec<ProjectMilestone>, Box<dyn std::error::Error>> {
+    let data = api::query_linear::<ProjectMilestonesResponse>(
+        "query ProjectMilestones($id: String!) { project(id: $id) { projectMilestones { nodes { id name targetDate description } } } }",
+        Some(json!({ "id": project_id })),
+    )
+    .await?;
+    Ok(data.project.project_milestones.nodes)
+}
+
+/// Resolves a milestone of the project by id or name.
+pub async fn resolve_milestone(project_id: &str, value: &str) -> Result<String, Box<dyn std::error::Error>> {
+    project_milestones(project_id)
+        .await?
+        .into_iter()
+        .find(|milestone| milestone.id == value || milestone.name.eq_ignore_ascii_case(value))
+        .map(|milestone| milestone.id)
+        .ok_or_else(|| format!("milestone not found: {}", value).into())
+}
+
+pub async fn team_states(team_id: &str) -> Result<Vec<WorkflowState>, Box<dyn std::error::Error>> {
+    let data = api::query_linear::<TeamStatesResponse>(
+        "query TeamStates($id: String!) { team(id: $id) { states { nodes { id name type color position } } } }",
+        Some(json!({ "id": team_id })),
+    )
+    .await?;
+
+    let mut states = data.team.states.nodes;
+    states.sort_by(|a, b| a.position.total_cmp(&b.position));
+    Ok(states)
+}
+
+/// Resolves a workflow state of the team by id, name or type (e.g. `started`).
+/// Types pick the first state of that type in workflow order.
+pub async fn resolve_state(team_id: &str, value: &str) -> Result<String, Box<dyn std::error::Error>> {
+    let states = team_states(team_id).await?;
+    pick_state(&states, value)
+        .map(|state| state.id.clone())
+        .ok_or_else(|| format!("workflow state not found: {}", value).into())
+}
+
+fn pick_state<'a>(states: &'a [WorkflowState], value: &str) -> Option<&'a WorkflowState> {
+    states
+        .iter()
+        .find(|state| state.id == value || state.name.eq_ignore_ascii_case(value))
+        .or_else(|| {
+            states
+                .iter()
+                .find(|state| state.state_type.eq_ignore_ascii_case(value))
+        })
+}
+
+/// Uses the given issue identifier, or falls back to the one in the current git branch.
+pub fn issue_or_current_branch(issue: &Option<String>) -> Result<String, Box<dyn std::error::Error>> {
+    if let Some(issue) = issue {
+        return Ok(issue.clone());
+    }
+
+    let branch = git::current_branch()
+        .map_err(|e| format!("no issue given and the git branch could not be read: {}", e))?;
+    git::issue_identifier(&branch)
+        .ok_or_else(|| format!("no issue given and none found in branch '{}'", branch).into())
+}
+
+/// The issue identifier in a branch name whose key belongs to one of the
+/// workspace's teams, so that `fix-404-page` or `hotfix/release-2` don't
+/// pass for issues.
+pub async fn branch_issue(branch: &str) -> Result<Option<String>, Box<dyn std::error::Error>> {
+    let keys: Vec<&str> = teams().await?.iter().filter_map(|team| team.key.as_deref()).collect();
+    Ok(pick_branch_issue(&git::issue_identifiers(branch), &keys))
+}
+
+fn pick_branch_issue(candidates: &[String], team_keys: &[&str]) -> Option<String> {
+    candidates
+        .iter()
+        .find(|candidate| {
+            candidate
+                .rsplit_once('-')
+                .is_some_and(|(key, _)| team_keys.iter().any(|team| team.eq_ignore_ascii_case(key)))
+        })
+        .cloned()
+}
+
+/// Parses a priority given as a number (0-4) or a name (`urgent`, `high`, ...).
+pub fn parse_priority(value: &str) -> Result<u8, String> {
+    match value.trim().to_ascii_lowercase().as_str() {
+        "0" | "none" | "no priority" => Ok(0),
+        "1" | "urgent" => Ok(1),
+        "2" | "high" => Ok(2),
+        "3" | "medium" | "normal" => Ok(3),
+        "4" | "low" => Ok(4),
+        other => Err(format!(
+            "invalid priority: {} (expected 0-4, none, urgent, high, medium or low)",
+            other
+        )),
+    }
+}
+
+/// Accepts a document id, slug id, or URL such as
+/// `https://linear.app/acme/document/design-spec-1a2b3c4d5e6f` (whose slug id is
+/// the part after the last `-`).
+pub fn document_id(value: &str) -> String {
+    let value = value.trim().trim_end_matches('/');
+    match value.split_once("/document/") {
+        Some((_, slug)) => {
+            let slug = slug.split(['/', '#', '?']).next().unwrap_or(slug);
+            slug.rsplit('-').next().unwrap_or(slug).to_string()
+        }
+        None => value.to_string(),
+    }
+}
+
+/// Checks that a date is given as `YYYY-MM-DD`, the format Linear's `TimelessDate` expects.
+pub fn parse_date(value: &str) -> Result<String, String> {
+    let value = value.trim();
+    let parts: Vec<&str> = value.split('-').collect();
+    let valid = matches!(parts.as_slice(), [year, month, day]
+        if year.len() == 4 && month.len() == 2 && day.len() == 2
+            && parts.iter().all(|part| part.chars().all(|c| c.is_ascii_digit()))
+            && (1..=12).contains(&month.parse::<u8>().unwrap_or(0))
+            && (1..=31).contains(&day.parse::<u8>().unwrap_or(0)));
+    if valid {
+        Ok(value.to_string())
+    } else {
+        Err(format!("invalid date: {} (expected YYYY-MM-DD)", value))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn state(id: &str, name: &str, state_type: &str, position: f64) -> WorkflowState {
+        WorkflowState {
+            id: id.to_string(),
+            name: name.to_string(),
+            state_type: state_type.to_string(),
+            color: None,
+            position,
+        }
+    }
+
+    #[test]
+    fn test_pick_state_by_name_then_type() {
+        let states = vec![
+            state("s1", "Todo", "unstarted", 1.0),
+            state("s2", "In Progress", "started", 2.0),
+            state("s3", "In Review", "started", 3.0),
+        ];
+        assert_eq!(pick_state(&states, "in review").unwrap().id, "s3");
+        assert_eq!(pick_state(&states, "started").unwrap().id, "s2");
+        assert_eq!(pick_state(&states, "s1").unwrap().id, "s1");
+        assert!(pick_state(&states, "done").is_none());
+    }
+
+    #[test]
+    fn test_pick_branch_issue_needs_a_team_key() {
+        let keys = ["ENG", "WEB"];
+        let pick = |branch: &str| pick_branch_issue(&git::issue_identifiers(branch), &keys);
+        assert_eq!(pick("kxzk/eng-123-fix-api"), Some("ENG-123".to_string()));
+        assert_eq!(pick("hotfix/fix-404-web-7"), Some("WEB-7".to_string()));
+        assert_eq!(pick("hotfix/release-2"), None);
+        assert_eq!(pick("fix-404-page"), None);
+    }
+
+    #[test]
+    fn test_parse_priority_numbers() {
+        assert_eq!(parse_priority("0"), Ok(0));
+        assert_eq!(parse_priority("4"), Ok(4));
+    }
+
+    #[test]
+    fn test_parse_priority_names() {
+        assert_eq!(parse_priority("Urgent"), Ok(1));
+        assert_eq!(parse_priority("high"), Ok(2));
+        assert_eq!(parse_priority("medium"), Ok(3));
+        assert_eq!(parse_priority(" low "), Ok(4));
+        assert_eq!(parse_priority("none"), Ok(0));
+    }
+
+    #[test]
+    fn test_document_id_from_url() {
+        assert_eq!(
+            document_id("https://linear.app/acme/document/design-spec-1a2b3c4d5e6f"),
+            "1a2b3c4d5e6f"
+        );
+        assert_eq!(document_id("https://linear.app/acme/document/spec-9f8e7d#intro"), "9f8e7d");
+        assert_eq!(document_id("doc-uuid-1"), "doc-uuid-1");
+    }
+
+    #[test]
+    fn test_parse_date() {
+        assert_eq!(parse_date(" 2026-03-31 "), Ok("2026-03-31".to_string()));
+        assert!(parse_date("2026-3-31").is_err());
+        assert!(parse_date("2026-13-01").is_err());
+        assert!(parse_date("next friday").is_err());
+    }
+
+    #[test]
+    fn test_parse_priority_invalid() {
+        assert!(parse_priority("5").is_err());
+        assert!(parse_priority("critical").unwrap_err().contains("critical"));
+    }
+}
@@ -0,0 +1,101 @@
+//! Snippets for `search` results: a short window of the description around
+//! the first matching term, with every term wrapped in `**`.
+
+const SNIPPET_WIDTH: usize = 120;
+
+fn terms(query: &str) -> Vec<String> {
+    query
+        .split(|c: char| c.is_whitespace() || c == '"')
+        .filter(|term| term.len() >= 2)
+        .map(|term| term.to_ascii_lowercase())
+        .collect()
+}
+
+/// The part of `text` around the first match of any word in `query`, or
+/// `None` when nothing matches.
+pub fn snippet(text: &str, query: &str) -> Option<String> {
+    let text = text.split_whitespace().collect::<Vec<_>>().join(" ");
+    let lower = text.to_ascii_lowercase();
+    let terms = terms(query);
+
+    let first = terms.iter().filter_map(|term| lower.find(term.as_str())).min()?;
+    let mut start = first.saturating_sub(SNIPPET_WIDTH / 3);
+    while !text.is_char_boundary(start) {
+        start -= 1;
+    }
+    let mut end = (start + SNIPPET_WIDTH).min(text.len());
+    while !text.is_char_boundary(end) {
+        end += 1;
+    }
+
+    let mut out = String::new();
+    if start > 0 {
+        out.push('…');
+    }
+    out += &highlight(&text[start..end], &terms);
+    if end < text.len() {
+        out.push('…');
+    }
+    Some(out)
+}
+
+fn highlight(text: &str, terms: &[String]) -> String {
+    let lower = text.to_ascii_lowercase();
+    let mut out = String::with_capacity(text.len());
+    let mut index = 0;
+    while index < text.len() {
+        let matched = terms
+            .iter()
+            .filter(|term| lower[index..].starts_with(term.as_str()))
+            .map(|term| term.len())
+            .max();
+        match matched {
+            Some(len) => {
+                out += "**";
+                out += &text[index..index + len];
+                out += "**";
+                index += len;
+            }
+            None => {
+                let c = text[index..].chars().next().unwrap_or_default();
+                out.push(c);
+                index += c.len_utf8();
+            }
+        }
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_snippet_highlights_terms() {
+        assert_eq!(
+            snippet("Checkout hits a Payment\n timeout after 30s", "payment timeout").unwrap(),
+            "Checkout hits a **Payment** **timeout** after 30s"
+        );
+    }
+
+    #[test]
+    fn test_snippet_windows_long_text() {
+        let text = format!("{} payment {}", "a ".repeat(100), "b ".repeat(100));
+        let snippet = snippet(&text, "payment").unwrap();
+        assert!(snippet.starts_with('…'));
+        assert!(snippet.ends_with('…'));
+        assert!(snippet.contains("**payment**"));
+    }
+
+    #[test]
+    fn test_snippet_without_match() {
+        assert_eq!(snippet("nothing relevant", "payment"), None);
+        assert_eq!(snippet("a b c", "a"), None);
+    }
+
+    #[test]
+    fn test_snippet_handles_multibyte_text() {
+        let text = format!("{}é payment", "ü".repeat(60));
+        assert!(snippet(&text, "payment").unwrap().ends_with("**payment**"));
+    }
+}
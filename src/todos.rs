@@ -0,0 +1,236 @@
+//! Finds `TODO`/`FIXME`/`HACK` comments in source files. A comment written as
+//! `TODO(ENG-123): ...` is tracked by that issue; anything else is untracked.
+
+use crate::git;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+const TAGS: &[&str] = &["TODO", "FIXME", "HACK"];
+const COMMENT_MARKERS: &[&str] = &["//", "#", "/*", "*", "--", "<!--", ";"];
+const SKIPPED_DIRS: &[&str] = &["target", "node_modules", "vendor", "dist", "build"];
+const MAX_FILE_BYTES: u64 = 1024 * 1024;
+
+#[derive(Debug, PartialEq)]
+pub struct Todo {
+    /// Path relative to the repository root (or the scanned directory outside git).
+    pub path: String,
+    /// 1-based line number.
+    pub line: usize,
+    pub tag: String,
+    pub reference: Option<String>,
+    pub text: String,
+}
+
+#[derive(Debug, PartialEq)]
+struct Marker<'a> {
+    tag: &'a str,
+    reference: Option<String>,
+    text: String,
+    /// Byte range of the tag plus any `(...)` suffix, replaced on rewrite.
+    span: (usize, usize),
+}
+
+fn parse_line(line: &str) -> Option<Marker<'_>> {
+    for tag in TAGS {
+        let mut from = 0;
+        while let Some(found) = line[from..].find(tag) {
+            let start = from + found;
+            let end = start + tag.len();
+            from = end;
+
+            let prefix = line[..start].trim_end();
+            if !COMMENT_MARKERS.iter().any(|marker| prefix.ends_with(marker)) {
+                continue;
+            }
+            if line[end..].starts_with(|c: char| c.is_alphanumeric() || c == '_') {
+                continue;
+            }
+
+            let mut rest = &line[end..];
+            let mut span_end = end;
+            let mut reference = None;
+            if let Some(inner) = rest.strip_prefix('(')
+                && let Some(close) = inner.find(')')
+            {
+                let value = inner[..close].trim();
+                reference = git::issue_identifier(value).filter(|id| id.eq_ignore_ascii_case(value));
+                span_end = end + close + 2;
+                rest = &inner[close + 1..];
+            }
+
+            let text = rest
+                .trim_start_matches(':')
+                .trim()
+                .trim_end_matches("-->")
+                .trim_end_matches("*/")
+                .trim()
+                .to_string();
+
+            return Some(Marker {
+                tag,
+                reference,
+                text,
+                span: (start, span_end),
+            });
+        }
+    }
+    None
+}
+
+pub fn scan_text(path: &str, content: &str) -> Vec<Todo> {
+    content
+        .lines()
+        .enumerate()
+        .filter_map(|(index, line)| {
+            parse_line(line).map(|marker| Todo {
+                path: path.to_string(),
+                line: index + 1,
+                tag: marker.tag.to_string(),
+                reference: marker.reference,
+                text: marker.text,
+            })
+        })
+        .collect()
+}
+
+/// Rewrites the marker on `line` to `TAG(identifier)`, keeping the rest intact.
+pub fn rewrite_line(line: &str, identifier: &str) -> String {
+    match parse_line(line) {
+        Some(marker) => format!(
+            "{}{}({}){}",
+            &line[..marker.span.0],
+            marker.tag,
+            identifier,
+            &line[marker.span.1..]
+        ),
+        None => line.to_string(),
+    }
+}
+
+/// Where scanned files live: `(root, display path relative to root)` pairs.
+/// Inside a git repository only tracked files are considered.
+pub fn list_files(dir: &Path) -> Result<(PathBuf, Vec<String>), Box<dyn std::error::Error>> {
+    if let Ok(root) = git::run_in(dir, &["rev-parse", "--show-toplevel"]) {
+        let files = git::run_in(dir, &["ls-files", "--full-name"])?;
+        return Ok((
+            PathBuf::from(root),
+            files.lines().map(String::from).collect(),
+        ));
+    }
+
+    let mut files = Vec::new();
+    walk(dir, dir, &mut files)?;
+    files.sort();
+    Ok((dir.to_path_buf(), files))
+}
+
+fn walk(root: &Path, dir: &Path, files: &mut Vec<String>) -> Result<(), Box<dyn std::error::Error>> {
+    for entry in fs::read_dir(dir)? {
+        let entry = entry?;
+        let name = entry.file_name();
+        let name = name.to_string_lossy();
+        if name.starts_with('.') {
+            continue;
+        }
+
+        let path = entry.path();
+        let file_type = entry.file_type()?;
+        if file_type.is_dir() {
+            if !SKIPPED_DIRS.contains(&name.as_ref()) {
+                walk(root, &path, files)?;
+            }
+        } else if file_type.is_file()
+            && let Ok(relative) = path.strip_prefix(root)
+        {
+            files.push(relative.to_string_lossy().replace('\\', "/"));
+        }
+    }
+    Ok(())
+}
+
+/// Scans every listed file, skipping large and non-UTF-8 files.
+pub fn scan(root: &Path, files: &[String]) -> Vec<Todo> {
+    files
+        .iter()
+        .filter(|file| {
+            fs::metadata(root.join(file)).is_ok_and(|meta| meta.is_file() && meta.len() <= MAX_FILE_BYTES)
+        })
+        .filter_map(|file| {
+            fs::read_to_string(root.join(file))
+                .ok()
+                .map(|content| scan_text(file, &content))
+        })
+        .flatten()
+        .collect()
+}
+
+/// Lines around `line` (1-based) for the issue description.
+pub fn context(content: &str, line: usize, radius: usize) -> String {
+    let first = line.saturating_sub(radius + 1);
+    content
+        .lines()
+        .skip(first)
+        .take(line + radius - first)
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_untracked_todo() {
+        let marker = parse_line("    // TODO: handle timeouts").unwrap();
+        assert_eq!(marker.tag, "TODO");
+        assert_eq!(marker.reference, None);
+        assert_eq!(marker.text, "handle timeouts");
+    }
+
+    #[test]
+    fn test_parse_tracked_todo() {
+        let marker = parse_line("# FIXME(eng-123): flaky retry").unwrap();
+        assert_eq!(marker.tag, "FIXME");
+        assert_eq!(marker.reference, Some("ENG-123".to_string()));
+        assert_eq!(marker.text, "flaky retry");
+    }
+
+    #[test]
+    fn test_parse_owner_is_not_a_reference() {
+        let marker = parse_line("/* HACK(alice) work around bug */").unwrap();
+        assert_eq!(marker.tag, "HACK");
+        assert_eq!(marker.reference, None);
+        assert_eq!(marker.text, "work around bug");
+    }
+
+    #[test]
+    fn test_parse_ignores_non_comments_and_longer_words() {
+        assert!(parse_line("let todo = \"TODO list\";").is_none());
+        assert!(parse_line("// TODOS are tracked elsewhere").is_none());
+        assert!(parse_line("fn hack() {}").is_none());
+    }
+
+    #[test]
+    fn test_scan_text_line_numbers() {
+        let todos = scan_text("src/lib.rs", "fn a() {}\n// TODO: one\n\n<!-- HACK: two -->\n");
+        assert_eq!(todos.len(), 2);
+        assert_eq!(todos[0].line, 2);
+        assert_eq!(todos[0].path, "src/lib.rs");
+        assert_eq!(todos[1].line, 4);
+        assert_eq!(todos[1].text, "two");
+    }
+
+    #[test]
+    fn test_rewrite_line() {
+        assert_eq!(rewrite_line("    // TODO: handle", "ENG-7"), "    // TODO(ENG-7): handle");
+        assert_eq!(rewrite_line("# FIXME(alice) later", "ENG-8"), "# FIXME(ENG-8) later");
+        assert_eq!(rewrite_line("plain line", "ENG-9"), "plain line");
+    }
+
+    #[test]
+    fn test_context() {
+        let content = "1\n2\n3\n4\n5\n6";
+        assert_eq!(context(content, 3, 1), "2\n3\n4");
+        assert_eq!(context(content, 1, 2), "1\n2\n3");
+    }
+}
@@ -28,6 +28,16 @@ pub struct TeamsData {
 pub struct Team {
     pub id: String,
     pub name: String,
+    pub key: Option<String>,
+    pub description: Option<String>,
+    pub members: Option<UserRefsData>,
+    #[serde(rename = "cyclesEnabled")]
+    pub cycles_enabled: Option<bool>,
+    /// Cycle length in weeks.
+    #[serde(rename = "cycleDuration")]
+    pub cycle_duration: Option<f64>,
+    #[serde(rename = "triageEnabled")]
+    pub triage_enabled: Option<bool>,
 }
 
 #[derive(Deserialize)]
@@ -44,6 +54,14 @@ pub struct ProjectsData {
 pub struct Project {
     pub id: String,
     pub name: String,
+    pub state: Option<String>,
+    pub lead: Option<UserRef>,
+    #[serde(rename = "targetDate")]
+    pub target_date: Option<String>,
+    /// Completion between 0 and 1.
+    pub progress: Option<f64>,
+    pub teams: Option<TeamRefsData>,
+    pub url: Option<String>,
 }
 
 #[derive(Deserialize)]
@@ -58,15 +76,703 @@ pub struct IssuePayload {
     pub issue: Option<Issue>,
 }
 
-#[derive(Deserialize, Serialize)]
+#[derive(Deserialize)]
+pub struct IssueUpdateResponse {
+    #[serde(rename = "issueUpdate")]
+    pub issue_update: IssuePayload,
+}
+
+#[derive(Deserialize, Serialize, Clone)]
 pub struct Issue {
     pub id: String,
+    pub identifier: Option<String>,
     pub title: String,
     pub url: String,
     #[serde(rename = "branchName")]
     pub branch_name: Option<String>,
 }
 
+#[derive(Deserialize)]
+pub struct IssuesResponse {
+    pub issues: IssuesData,
+}
+
+#[derive(Deserialize)]
+pub struct IssueSearchResponse {
+    #[serde(rename = "searchIssues")]
+    pub search_issues: IssuesData,
+}
+
+#[derive(Deserialize)]
+pub struct ProjectCreateResponse {
+    #[serde(rename = "projectCreate")]
+    pub project_create: ProjectPayload,
+}
+
+#[derive(Deserialize)]
+pub struct ProjectUpdateResponse {
+    #[serde(rename = "projectUpdate")]
+    pub project_update: ProjectPayload,
+}
+
+#[derive(Deserialize)]
+pub struct ProjectPayload {
+    pub success: bool,
+    pub project: Option<Project>,
+}
+
+#[derive(Deserialize)]
+pub struct ProjectArchiveResponse {
+    #[serde(rename = "projectArchive")]
+    pub project_archive: ArchivePayload,
+}
+
+#[derive(Deserialize)]
+pub struct ArchivePayload {
+    pub success: bool,
+}
+
+#[derive(Deserialize)]
+pub struct ProjectDetailResponse {
+    pub project: ProjectDetail,
+}
+
+#[derive(Deserialize)]
+pub struct ProjectDetail {
+    pub id: String,
+    pub name: String,
+    pub description: Option<String>,
+    pub content: Option<String>,
+    pub state: Option<String>,
+    pub progress: Option<f64>,
+    #[serde(rename = "startDate")]
+    pub start_date: Option<String>,
+    #[serde(rename = "targetDate")]
+    pub target_date: Option<String>,
+    pub url: String,
+    pub lead: Option<UserRef>,
+    pub teams: TeamRefsData,
+    pub members: UserRefsData,
+    #[serde(rename = "projectMilestones")]
+    pub project_milestones: ProjectMilestonesData,
+    pub issues: IssueStatesData,
+}
+
+#[derive(Deserialize)]
+pub struct CustomViewsResponse {
+    #[serde(rename = "customViews")]
+    pub custom_views: CustomViewsData,
+}
+
+#[derive(Deserialize)]
+pub struct CustomViewsData {
+    pub nodes: Vec<CustomView>,
+}
+
+#[derive(Deserialize)]
+pub struct CustomView {
+    pub id: String,
+    pub name: String,
+    pub shared: Option<bool>,
+}
+
+#[derive(Deserialize)]
+pub struct CustomViewIssuesResponse {
+    #[serde(rename = "customView")]
+    pub custom_view: CustomViewIssues,
+}
+
+#[derive(Deserialize)]
+pub struct CustomViewIssues {
+    pub issues: IssuesData,
+}
+
+#[derive(Deserialize)]
+pub struct InitiativesResponse {
+    pub initiatives: InitiativesData,
+}
+
+#[derive(Deserialize)]
+pub struct InitiativesData {
+    pub nodes: Vec<Initiative>,
+}
+
+#[derive(Deserialize)]
+pub struct Initiative {
+    pub id: String,
+    pub name: String,
+    pub status: Option<String>,
+    #[serde(rename = "targetDate")]
+    pub target_date: Option<String>,
+    pub owner: Option<UserRef>,
+    pub url: Option<String>,
+}
+
+#[derive(Deserialize)]
+pub struct InitiativeDetailResponse {
+    pub initiative: InitiativeDetail,
+}
+
+#[derive(Deserialize)]
+pub struct InitiativeDetail {
+    pub id: String,
+    pub name: String,
+    pub description: Option<String>,
+    pub status: Option<String>,
+    #[serde(rename = "targetDate")]
+    pub target_date: Option<String>,
+    pub owner: Option<UserRef>,
+    pub url: Option<String>,
+    pub projects: ProjectsData,
+}
+
+#[derive(Deserialize)]
+pub struct InitiativeToProjectCreateResponse {
+    #[serde(rename = "initiativeToProjectCreate")]
+    pub initiative_to_project_create: InitiativeToProjectPayload,
+}
+
+#[derive(Deserialize)]
+pub struct InitiativeToProjectPayload {
+    pub success: bool,
+}
+
+#[derive(Deserialize)]
+pub struct ProjectUpdateCreateResponse {
+    #[serde(rename = "projectUpdateCreate")]
+    pub project_update_create: ProjectStatusUpdatePayload,
+}
+
+#[derive(Deserialize)]
+pub struct ProjectStatusUpdatePayload {
+    pub success: bool,
+    #[serde(rename = "projectUpdate")]
+    pub project_update: Option<ProjectStatusUpdate>,
+}
+
+#[derive(Deserialize)]
+pub struct ProjectStatusUpdatesResponse {
+    pub project: ProjectStatusUpdates,
+}
+
+#[derive(Deserialize)]
+pub struct ProjectStatusUpdates {
+    #[serde(rename = "projectUpdates")]
+    pub project_updates: ProjectStatusUpdatesData,
+}
+
+#[derive(Deserialize)]
+pub struct ProjectStatusUpdatesData {
+    pub nodes: Vec<ProjectStatusUpdate>,
+}
+
+/// A `ProjectUpdate` in Linear's schema: a health report posted on a project.
+#[derive(Deserialize)]
+pub struct ProjectStatusUpdate {
+    pub id: String,
+    pub body: Option<String>,
+    pub health: Option<String>,
+    #[serde(rename = "createdAt")]
+    pub created_at: String,
+    pub url: Option<String>,
+    pub user: Option<UserRef>,
+}
+
+#[derive(Deserialize)]
+pub struct ProjectMilestonesResponse {
+    pub project: ProjectMilestones,
+}
+
+#[derive(Deserialize)]
+pub struct ProjectMilestones {
+    #[serde(rename = "projectMilestones")]
+    pub project_milestones: ProjectMilestonesData,
+}
+
+#[derive(Deserialize)]
+pub struct ProjectMilestoneCreateResponse {
+    #[serde(rename = "projectMilestoneCreate")]
+    pub project_milestone_create: ProjectMilestonePayload,
+}
+
+#[derive(Deserialize)]
+pub struct ProjectMilestonePayload {
+    pub success: bool,
+    #[serde(rename = "projectMilestone")]
+    pub project_milestone: Option<ProjectMilestone>,
+}
+
+#[derive(Deserialize)]
+pub struct ProjectMilestonesData {
+    pub nodes: Vec<ProjectMilestone>,
+}
+
+#[derive(Deserialize)]
+pub struct ProjectMilestone {
+    pub id: String,
+    pub name: String,
+    #[serde(rename = "targetDate")]
+    pub target_date: Option<String>,
+    pub description: Option<String>,
+}
+
+#[derive(Deserialize)]
+pub struct IssueStatesData {
+    pub nodes: Vec<IssueStateNode>,
+}
+
+#[derive(Deserialize)]
+pub struct IssueStateNode {
+    pub state: Option<WorkflowStateRef>,
+}
+
+#[derive(Deserialize)]
+pub struct DocumentSearchResponse {
+    #[serde(rename = "searchDocuments")]
+    pub search_documents: DocumentsData,
+}
+
+#[derive(Deserialize)]
+pub struct DocumentsData {
+    pub nodes: Vec<DocumentSummary>,
+}
+
+#[derive(Deserialize)]
+pub struct DocumentSummary {
+    pub id: String,
+    pub title: String,
+    pub url: String,
+    pub content: Option<String>,
+    pub project: Option<ProjectRef>,
+    #[serde(rename = "updatedAt")]
+    pub updated_at: Option<String>,
+}
+
+#[derive(Deserialize)]
+pub struct DocumentsResponse {
+    pub documents: DocumentsData,
+}
+
+#[derive(Deserialize)]
+pub struct DocumentResponse {
+    pub document: DocumentSummary,
+}
+
+#[derive(Deserialize)]
+pub struct DocumentCreateResponse {
+    #[serde(rename = "documentCreate")]
+    pub document_create: DocumentPayload,
+}
+
+#[derive(Deserialize)]
+pub struct DocumentUpdateResponse {
+    #[serde(rename = "documentUpdate")]
+    pub document_update: DocumentPayload,
+}
+
+#[derive(Deserialize)]
+pub struct DocumentPayload {
+    pub success: bool,
+    pub document: Option<DocumentSummary>,
+}
+
+#[derive(Deserialize)]
+pub struct ProjectSearchResponse {
+    #[serde(rename = "searchProjects")]
+    pub search_projects: ProjectSummariesData,
+}
+
+#[derive(Deserialize)]
+pub struct ProjectSummariesData {
+    pub nodes: Vec<ProjectSummary>,
+}
+
+#[derive(Deserialize)]
+pub struct ProjectSummary {
+    pub id: String,
+    pub name: String,
+    pub url: String,
+    pub description: Option<String>,
+    pub state: Option<String>,
+}
+
+#[derive(Deserialize)]
+pub struct IssuesData {
+    pub nodes: Vec<IssueSummary>,
+    #[serde(rename = "pageInfo")]
+    pub page_info: Option<PageInfo>,
+}
+
+#[derive(Deserialize)]
+pub struct PageInfo {
+    #[serde(rename = "hasNextPage")]
+    pub has_next_page: bool,
+    #[serde(rename = "endCursor")]
+    pub end_cursor: Option<String>,
+}
+
+#[derive(Deserialize, Clone)]
+pub struct IssueSummary {
+    pub id: String,
+    pub identifier: String,
+    pub title: String,
+    pub url: String,
+    #[serde(rename = "branchName")]
+    pub branch_name: Option<String>,
+    pub description: Option<String>,
+    pub state: Option<WorkflowStateRef>,
+    pub team: Option<TeamRef>,
+    pub assignee: Option<UserRef>,
+    #[serde(rename = "priorityLabel")]
+    pub priority_label: Option<String>,
+}
+
+impl From<IssueSummary> for Issue {
+    fn from(summary: IssueSummary) -> Self {
+        Issue {
+            id: summary.id,
+            identifier: Some(summary.identifier),
+            title: summary.title,
+            url: summary.url,
+            branch_name: summary.branch_name,
+        }
+    }
+}
+
+#[derive(Deserialize, Clone)]
+pub struct WorkflowStateRef {
+    pub name: String,
+    #[serde(rename = "type")]
+    pub state_type: String,
+}
+
+#[derive(Deserialize)]
+pub struct CommentCreateResponse {
+    #[serde(rename = "commentCreate")]
+    pub comment_create: CommentPayload,
+}
+
+#[derive(Deserialize)]
+pub struct CommentPayload {
+    pub success: bool,
+    pub comment: Option<Comment>,
+}
+
+#[derive(Deserialize)]
+pub struct Comment {
+    pub id: String,
+    pub url: Option<String>,
+}
+
+/// Issue fields as written by people and scripts: names instead of ids,
+/// priorities as words, labels as a list or comma-separated string.
+#[derive(Deserialize, Default, Debug, PartialEq)]
+#[serde(deny_unknown_fields)]
+pub struct IssueSpec {
+    pub title: Option<String>,
+    pub description: Option<String>,
+    pub team: Option<String>,
+    pub project: Option<String>,
+    #[serde(default, deserialize_with = "string_or_list")]
+    pub labels: Vec<String>,
+    #[serde(default, deserialize_with = "string_or_number")]
+    pub priority: Option<String>,
+    pub assignee: Option<String>,
+    #[serde(default, deserialize_with = "string_or_list")]
+    pub subscribers: Vec<String>,
+    pub milestone: Option<String>,
+    pub parent: Option<String>,
+    #[serde(default, deserialize_with = "string_or_number")]
+    pub estimate: Option<String>,
+    pub idempotency_key: Option<String>,
+}
+
+fn string_or_list<'de, D>(deserializer: D) -> Result<Vec<String>, D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    Ok(match Value::deserialize(deserializer)? {
+        Value::Array(items) => items
+            .into_iter()
+            .filter_map(|item| item.as_str().map(|s| s.trim().to_string()))
+            .filter(|item| !item.is_empty())
+            .collect(),
+        Value::String(s) => s
+            .split(',')
+            .map(|item| item.trim().to_string())
+            .filter(|item| !item.is_empty())
+            .collect(),
+        _ => Vec::new(),
+    })
+}
+
+fn string_or_number<'de, D>(deserializer: D) -> Result<Option<String>, D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    Ok(match Value::deserialize(deserializer)? {
+        Value::String(s) => Some(s),
+        Value::Number(n) => Some(n.to_string()),
+        _ => None,
+    })
+}
+
+#[derive(Deserialize)]
+pub struct IssueResponse {
+    pub issue: IssueDetail,
+}
+
+#[derive(Deserialize)]
+pub struct IssueDetail {
+    pub id: String,
+    pub identifier: String,
+    pub title: String,
+    pub url: String,
+    #[serde(rename = "branchName")]
+    pub branch_name: Option<String>,
+    pub description: Option<String>,
+    #[serde(rename = "priorityLabel")]
+    pub priority_label: Option<String>,
+    pub team: TeamRef,
+    pub state: Option<WorkflowStateRef>,
+    pub assignee: Option<UserRef>,
+    pub project: Option<ProjectRef>,
+    pub labels: Option<LabelRefsData>,
+    pub comments: Option<CommentsData>,
+}
+
+#[derive(Deserialize, Clone)]
+pub struct UserRef {
+    pub id: String,
+    pub name: String,
+}
+
+#[derive(Deserialize)]
+pub struct ProjectRef {
+    pub id: String,
+    pub name: String,
+}
+
+#[derive(Deserialize)]
+pub struct LabelRefsData {
+    pub nodes: Vec<LabelRef>,
+}
+
+#[derive(Deserialize)]
+pub struct LabelRef {
+    pub name: String,
+}
+
+#[derive(Deserialize)]
+pub struct CommentsData {
+    pub nodes: Vec<CommentDetail>,
+}
+
+#[derive(Deserialize)]
+pub struct CommentDetail {
+    pub body: String,
+    #[serde(rename = "createdAt")]
+    pub created_at: String,
+    pub user: Option<UserRef>,
+}
+
+#[derive(Deserialize)]
+pub struct TeamStatesResponse {
+    pub team: TeamStates,
+}
+
+#[derive(Deserialize)]
+pub struct TeamStates {
+    pub states: WorkflowStatesData,
+}
+
+#[derive(Deserialize)]
+pub struct WorkflowStatesData {
+    pub nodes: Vec<WorkflowState>,
+}
+
+#[derive(Deserialize)]
+pub struct WorkflowState {
+    pub id: String,
+    pub name: String,
+    #[serde(rename = "type")]
+    pub state_type: String,
+    pub color: Option<String>,
+    pub position: f64,
+}
+
+#[derive(Deserialize)]
+pub struct FileUploadResponse {
+    #[serde(rename = "fileUpload")]
+    pub file_upload: UploadPayload,
+}
+
+#[derive(Deserialize)]
+pub struct UploadPayload {
+    pub success: bool,
+    #[serde(rename = "uploadFile")]
+    pub upload_file: Option<UploadFile>,
+}
+
+#[derive(Deserialize)]
+pub struct UploadFile {
+    #[serde(rename = "uploadUrl")]
+    pub upload_url: String,
+    #[serde(rename = "assetUrl")]
+    pub asset_url: String,
+    pub headers: Vec<UploadFileHeader>,
+}
+
+#[derive(Deserialize)]
+pub struct UploadFileHeader {
+    pub key: String,
+    pub value: String,
+}
+
+#[derive(Deserialize)]
+pub struct AttachmentCreateResponse {
+    #[serde(rename = "attachmentCreate")]
+    pub attachment_create: AttachmentPayload,
+}
+
+#[derive(Deserialize)]
+pub struct AttachmentPayload {
+    pub success: bool,
+    pub attachment: Option<Attachment>,
+}
+
+#[derive(Deserialize)]
+pub struct Attachment {
+    pub id: String,
+    pub title: String,
+    pub url: String,
+}
+
+#[derive(Deserialize)]
+pub struct IssueLookupResponse {
+    pub issue: IssueRef,
+}
+
+#[derive(Deserialize)]
+pub struct IssueRef {
+    pub id: String,
+    pub identifier: String,
+}
+
+#[derive(Deserialize)]
+pub struct ViewerResponse {
+    pub viewer: User,
+}
+
+#[derive(Deserialize)]
+pub struct WhoamiResponse {
+    pub viewer: ViewerDetail,
+}
+
+#[derive(Deserialize)]
+pub struct ViewerDetail {
+    pub id: String,
+    pub name: String,
+    #[serde(rename = "displayName")]
+    pub display_name: Option<String>,
+    pub email: Option<String>,
+    pub organization: Organization,
+    pub teams: TeamsData,
+}
+
+#[derive(Deserialize)]
+pub struct Organization {
+    pub name: String,
+    #[serde(rename = "urlKey")]
+    pub url_key: String,
+}
+
+#[derive(Deserialize)]
+pub struct UsersResponse {
+    pub users: UsersData,
+}
+
+#[derive(Deserialize)]
+pub struct UsersData {
+    pub nodes: Vec<User>,
+}
+
+#[derive(Deserialize)]
+pub struct User {
+    pub id: String,
+    pub name: String,
+    #[serde(rename = "displayName")]
+    pub display_name: Option<String>,
+    pub email: Option<String>,
+}
+
+#[derive(Deserialize)]
+pub struct MembersResponse {
+    pub users: MembersData,
+}
+
+#[derive(Deserialize)]
+pub struct TeamMembersResponse {
+    pub team: TeamMembers,
+}
+
+#[derive(Deserialize)]
+pub struct TeamMembers {
+    pub members: MembersData,
+}
+
+#[derive(Deserialize)]
+pub struct MembersData {
+    pub nodes: Vec<Member>,
+}
+
+#[derive(Deserialize)]
+pub struct Member {
+    pub id: String,
+    pub name: String,
+    #[serde(rename = "displayName")]
+    pub display_name: Option<String>,
+    pub email: Option<String>,
+    pub active: bool,
+    pub admin: bool,
+    pub guest: bool,
+}
+
+#[derive(Deserialize)]
+pub struct IssueLabelsResponse {
+    #[serde(rename = "issueLabels")]
+    pub issue_labels: IssueLabelsData,
+}
+
+#[derive(Deserialize)]
+pub struct IssueLabelsData {
+    pub nodes: Vec<IssueLabel>,
+}
+
+#[derive(Deserialize)]
+pub struct IssueLabel {
+    pub id: String,
+    pub name: String,
+    pub team: Option<TeamRef>,
+}
+
+#[derive(Deserialize)]
+pub struct UserRefsData {
+    pub nodes: Vec<UserRef>,
+}
+
+#[derive(Deserialize)]
+pub struct TeamRefsData {
+    pub nodes: Vec<TeamRef>,
+}
+
+#[derive(Deserialize, Clone)]
+pub struct TeamRef {
+    pub id: String,
+    pub key: Option<String>,
+    pub name: Option<String>,
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -189,12 +895,14 @@ mod tests {
         let issue = response.issue_create.issue.unwrap();
         assert_eq!(issue.id, "issue-no-branch");
         assert_eq!(issue.branch_name, None);
+        assert_eq!(issue.identifier, None);
     }
 
     #[test]
     fn test_issue_serialization_with_branch_name() {
         let issue = Issue {
             id: "test-id".to_string(),
+            identifier: None,
             title: "Test Title".to_string(),
             url: "https://linear.app/test".to_string(),
             branch_name: Some("feat/test-branch".to_string()),
@@ -211,6 +919,7 @@ mod tests {
     fn test_issue_serialization_without_branch_name() {
         let issue = Issue {
             id: "test-id".to_string(),
+            identifier: None,
             title: "Test Title".to_string(),
             url: "https://linear.app/test".to_string(),
             branch_name: None,
@@ -223,6 +932,419 @@ mod tests {
         assert!(json["branchName"].is_null());
     }
 
+    #[test]
+    fn test_team_deserialization_with_key() {
+        let json = json!({"id": "team-1", "name": "Engineering", "key": "ENG"});
+        let team: Team = serde_json::from_value(json).unwrap();
+        assert_eq!(team.key, Some("ENG".to_string()));
+    }
+
+    #[test]
+    fn test_issue_update_response_deserialization() {
+        let json = json!({
+            "issueUpdate": {
+                "success": true,
+                "issue": {
+                    "id": "issue-123",
+                    "identifier": "ENG-123",
+                    "title": "Updated",
+                    "url": "https://linear.app/issue-123"
+                }
+            }
+        });
+        let response: IssueUpdateResponse = serde_json::from_value(json).unwrap();
+        assert!(response.issue_update.success);
+        let issue = response.issue_update.issue.unwrap();
+        assert_eq!(issue.identifier, Some("ENG-123".to_string()));
+    }
+
+    #[test]
+    fn test_issue_labels_response_deserialization() {
+        let json = json!({
+            "issueLabels": {
+                "nodes": [
+                    {"id": "label-1", "name": "bug", "team": null},
+                    {"id": "label-2", "name": "api", "team": {"id": "team-1"}}
+                ]
+            }
+        });
+        let response: IssueLabelsResponse = serde_json::from_value(json).unwrap();
+        assert_eq!(response.issue_labels.nodes.len(), 2);
+        assert!(response.issue_labels.nodes[0].team.is_none());
+        assert_eq!(response.issue_labels.nodes[1].team.as_ref().unwrap().id, "team-1");
+    }
+
+    #[test]
+    fn test_issue_spec_deserialization() {
+        let json = json!({
+            "title": "Fix login",
+            "team": "ENG",
+            "labels": ["bug", "auth"],
+            "priority": 2,
+            "estimate": "3"
+        });
+        let spec: IssueSpec = serde_json::from_value(json).unwrap();
+        assert_eq!(spec.title, Some("Fix login".to_string()));
+        assert_eq!(spec.labels, vec!["bug", "auth"]);
+        assert_eq!(spec.priority, Some("2".to_string()));
+        assert_eq!(spec.estimate, Some("3".to_string()));
+        assert!(spec.assignee.is_none());
+    }
+
+    #[test]
+    fn test_issue_spec_labels_from_string() {
+        let spec: IssueSpec = serde_json::from_value(json!({"labels": "bug, auth,"})).unwrap();
+        assert_eq!(spec.labels, vec!["bug", "auth"]);
+    }
+
+    #[test]
+    fn test_issue_spec_rejects_unknown_fields() {
+        let result: Result<IssueSpec, _> = serde_json::from_value(json!({"assigne": "me"}));
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_issue_search_response_deserialization() {
+        let json = json!({
+            "searchIssues": {
+                "nodes": [{
+                    "id": "issue-1",
+                    "identifier": "ENG-1",
+                    "title": "Login fails",
+                    "url": "https://linear.app/eng-1",
+                    "state": {"name": "Todo", "type": "unstarted"}
+                }]
+            }
+        });
+        let response: IssueSearchResponse = serde_json::from_value(json).unwrap();
+        let issue = &response.search_issues.nodes[0];
+        assert_eq!(issue.identifier, "ENG-1");
+        assert_eq!(issue.state.as_ref().unwrap().state_type, "unstarted");
+    }
+
+    #[test]
+    fn test_document_and_project_search_deserialization() {
+        let documents: DocumentSearchResponse = serde_json::from_value(json!({
+            "searchDocuments": {
+                "nodes": [{"id": "doc-1", "title": "Onboarding", "url": "https://linear.app/doc-1", "content": null}]
+            }
+        }))
+        .unwrap();
+        assert_eq!(documents.search_documents.nodes[0].title, "Onboarding");
+
+        let projects: ProjectSearchResponse = serde_json::from_value(json!({
+            "searchProjects": {
+                "nodes": [{"id": "p-1", "name": "API v2", "url": "https://linear.app/p-1", "state": "started"}]
+            }
+        }))
+        .unwrap();
+        assert_eq!(projects.search_projects.nodes[0].state, Some("started".to_string()));
+    }
+
+    #[test]
+    fn test_whoami_response_deserialization() {
+        let response: WhoamiResponse = serde_json::from_value(json!({
+            "viewer": {
+                "id": "user-1",
+                "name": "Kade",
+                "displayName": "kade",
+                "email": "kade@example.com",
+                "organization": {"name": "Acme", "urlKey": "acme"},
+                "teams": {"nodes": [{"id": "team-1", "name": "Engineering", "key": "ENG"}]}
+            }
+        }))
+        .unwrap();
+        assert_eq!(response.viewer.organization.url_key, "acme");
+        assert_eq!(response.viewer.teams.nodes[0].key, Some("ENG".to_string()));
+    }
+
+    #[test]
+    fn test_team_members_response_deserialization() {
+        let response: TeamMembersResponse = serde_json::from_value(json!({
+            "team": {
+                "members": {
+                    "nodes": [{
+                        "id": "user-1",
+                        "name": "Kade",
+                        "displayName": "kade",
+                        "email": null,
+                        "active": true,
+                        "admin": false,
+                        "guest": true
+                    }]
+                }
+            }
+        }))
+        .unwrap();
+        let member = &response.team.members.nodes[0];
+        assert_eq!(member.display_name, Some("kade".to_string()));
+        assert!(member.active && member.guest && !member.admin);
+    }
+
+    #[test]
+    fn test_detailed_project_deserialization() {
+        let response: ProjectsResponse = serde_json::from_value(json!({
+            "projects": {
+                "nodes": [{
+                    "id": "p-1",
+                    "name": "API v2",
+                    "state": "started",
+                    "lead": {"id": "user-1", "name": "Kade"},
+                    "targetDate": "2026-12-01",
+                    "progress": 0.42,
+                    "teams": {"nodes": [{"id": "team-1", "key": "ENG"}]},
+                    "url": "https://linear.app/acme/project/api-v2"
+                }]
+            }
+        }))
+        .unwrap();
+        let project = &response.projects.nodes[0];
+        assert_eq!(project.lead.as_ref().unwrap().name, "Kade");
+        assert_eq!(project.progress, Some(0.42));
+        assert_eq!(project.teams.as_ref().unwrap().nodes[0].key, Some("ENG".to_string()));
+    }
+
+    #[test]
+    fn test_project_detail_deserialization() {
+        let response: ProjectDetailResponse = serde_json::from_value(json!({
+            "project": {
+                "id": "p-1",
+                "name": "API v2",
+                "state": "started",
+                "url": "https://linear.app/acme/project/api-v2",
+                "lead": null,
+                "teams": {"nodes": [{"id": "team-1", "key": "ENG"}]},
+                "members": {"nodes": [{"id": "user-1", "name": "Kade"}]},
+                "projectMilestones": {"nodes": [{"id": "m-1", "name": "Beta", "targetDate": "2026-11-01"}]},
+                "issues": {"nodes": [{"state": {"name": "Done", "type": "completed"}}]}
+            }
+        }))
+        .unwrap();
+        let project = response.project;
+        assert_eq!(project.project_milestones.nodes[0].name, "Beta");
+        assert_eq!(project.issues.nodes[0].state.as_ref().unwrap().state_type, "completed");
+        assert!(project.start_date.is_none());
+    }
+
+    #[test]
+    fn test_project_milestone_create_deserialization() {
+        let response: ProjectMilestoneCreateResponse = serde_json::from_value(json!({
+            "projectMilestoneCreate": {
+                "success": true,
+                "projectMilestone": {"id": "m-1", "name": "Beta", "targetDate": "2026-11-01"}
+            }
+        }))
+        .unwrap();
+        let milestone = response.project_milestone_create.project_milestone.unwrap();
+        assert_eq!(milestone.target_date, Some("2026-11-01".to_string()));
+    }
+
+    #[test]
+    fn test_project_status_updates_deserialization() {
+        let response: ProjectStatusUpdatesResponse = serde_json::from_value(json!({
+            "project": {
+                "projectUpdates": {
+                    "nodes": [{
+                        "id": "pu-1",
+                        "body": "Shipped beta",
+                        "health": "onTrack",
+                        "createdAt": "2026-10-12T09:00:00.000Z",
+                        "user": {"id": "user-1", "name": "Kade"}
+                    }]
+                }
+            }
+        }))
+        .unwrap();
+        let update = &response.project.project_updates.nodes[0];
+        assert_eq!(update.health, Some("onTrack".to_string()));
+        assert!(update.url.is_none());
+    }
+
+    #[test]
+    fn test_initiative_detail_deserialization() {
+        let response: InitiativeDetailResponse = serde_json::from_value(json!({
+            "initiative": {
+                "id": "i-1",
+                "name": "Self-serve onboarding",
+                "status": "Active",
+                "targetDate": "2027-03-31",
+                "owner": {"id": "user-1", "name": "Kade"},
+                "projects": {"nodes": [{"id": "p-1", "name": "API v2", "state": "started", "progress": 0.5}]}
+            }
+        }))
+        .unwrap();
+        let initiative = response.initiative;
+        assert_eq!(initiative.status, Some("Active".to_string()));
+        assert_eq!(initiative.projects.nodes[0].progress, Some(0.5));
+        assert!(initiative.description.is_none());
+    }
+
+    #[test]
+    fn test_document_response_deserialization() {
+        let response: DocumentResponse = serde_json::from_value(json!({
+            "document": {
+                "id": "doc-1",
+                "title": "Design spec",
+                "url": "https://linear.app/acme/document/design-spec-1a2b3c",
+                "content": "## Goals",
+                "project": {"id": "p-1", "name": "API v2"},
+                "updatedAt": "2026-10-12T09:00:00.000Z"
+            }
+        }))
+        .unwrap();
+        assert_eq!(response.document.project.unwrap().name, "API v2");
+        assert_eq!(response.document.content, Some("## Goals".to_string()));
+    }
+
+    #[test]
+    fn test_file_upload_response_deserialization() {
+        let response: FileUploadResponse = serde_json::from_value(json!({
+            "fileUpload": {
+                "success": true,
+                "uploadFile": {
+                    "uploadUrl": "https://storage.example.com/signed",
+                    "assetUrl": "https://uploads.linear.app/asset",
+                    "headers": [{"key": "x-goog-meta-id", "value": "abc"}]
+                }
+            }
+        }))
+        .unwrap();
+        let upload = response.file_upload.upload_file.unwrap();
+        assert_eq!(upload.asset_url, "https://uploads.linear.app/asset");
+        assert_eq!(upload.headers[0].key, "x-goog-meta-id");
+    }
+
+    #[test]
+    fn test_custom_views_deserialization() {
+        let json = json!({
+            "customViews": {
+                "nodes": [{
+                    "id": "view-1",
+                    "name": "My Bugs",
+                    "shared": true
+                }]
+            }
+        });
+        let response: CustomViewsResponse = serde_json::from_value(json).unwrap();
+        let view = &response.custom_views.nodes[0];
+        assert_eq!(view.name, "My Bugs");
+        assert_eq!(view.shared, Some(true));
+
+        let json = json!({
+            "customView": {
+                "issues": {
+                    "nodes": [{"id": "issue-1", "identifier": "ENG-1", "title": "Crash", "url": "u"}],
+                    "pageInfo": {"hasNextPage": false, "endCursor": null}
+                }
+            }
+        });
+        let response: CustomViewIssuesResponse = serde_json::from_value(json).unwrap();
+        assert_eq!(response.custom_view.issues.nodes[0].identifier, "ENG-1");
+    }
+
+    #[test]
+    fn test_issues_page_deserialization() {
+        let json = json!({
+            "issues": {
+                "nodes": [{
+                    "id": "issue-1",
+                    "identifier": "ENG-1",
+                    "title": "Stale",
+                    "url": "https://linear.app/eng-1",
+                    "team": {"id": "team-1"},
+                    "state": {"name": "Backlog", "type": "backlog"}
+                }],
+                "pageInfo": {"hasNextPage": true, "endCursor": "cursor-1"}
+            }
+        });
+        let response: IssuesResponse = serde_json::from_value(json).unwrap();
+        assert_eq!(response.issues.nodes[0].team.as_ref().unwrap().id, "team-1");
+        let page_info = response.issues.page_info.unwrap();
+        assert!(page_info.has_next_page);
+        assert_eq!(page_info.end_cursor.as_deref(), Some("cursor-1"));
+    }
+
+    #[test]
+    fn test_issue_from_summary() {
+        let summary: IssueSummary = serde_json::from_value(json!({
+            "id": "issue-1",
+            "identifier": "ENG-1",
+            "title": "Login fails",
+            "url": "https://linear.app/eng-1",
+            "branchName": "eng-1-login-fails"
+        }))
+        .unwrap();
+        let issue = Issue::from(summary);
+        assert_eq!(issue.identifier, Some("ENG-1".to_string()));
+        assert_eq!(issue.branch_name, Some("eng-1-login-fails".to_string()));
+    }
+
+    #[test]
+    fn test_issue_response_deserialization() {
+        let json = json!({
+            "issue": {
+                "id": "issue-1",
+                "identifier": "ENG-1",
+                "title": "Login fails",
+                "url": "https://linear.app/eng-1",
+                "branchName": "eng-1-login-fails",
+                "description": "Steps to reproduce",
+                "priorityLabel": "High",
+                "team": {"id": "team-1", "key": "ENG", "name": "Engineering"},
+                "state": {"name": "Todo", "type": "unstarted"},
+                "assignee": null,
+                "project": {"id": "proj-1", "name": "Auth"},
+                "labels": {"nodes": [{"name": "bug"}]},
+                "comments": {"nodes": [{
+                    "body": "Seen on Safari",
+                    "createdAt": "2025-01-01T00:00:00.000Z",
+                    "user": {"id": "user-1", "name": "Alice"}
+                }]}
+            }
+        });
+        let response: IssueResponse = serde_json::from_value(json).unwrap();
+        let issue = response.issue;
+        assert_eq!(issue.identifier, "ENG-1");
+        assert_eq!(issue.team.key, Some("ENG".to_string()));
+        assert!(issue.assignee.is_none());
+        assert_eq!(issue.project.unwrap().name, "Auth");
+        assert_eq!(issue.labels.unwrap().nodes[0].name, "bug");
+        let comments = issue.comments.unwrap().nodes;
+        assert_eq!(comments[0].user.as_ref().unwrap().name, "Alice");
+    }
+
+    #[test]
+    fn test_team_states_response_deserialization() {
+        let json = json!({
+            "team": {
+                "states": {
+                    "nodes": [
+                        {"id": "s1", "name": "Todo", "type": "unstarted", "position": 1.0},
+                        {"id": "s2", "name": "In Progress", "type": "started", "position": 2}
+                    ]
+                }
+            }
+        });
+        let response: TeamStatesResponse = serde_json::from_value(json).unwrap();
+        let states = &response.team.states.nodes;
+        assert_eq!(states.len(), 2);
+        assert_eq!(states[1].state_type, "started");
+        assert_eq!(states[1].position, 2.0);
+    }
+
+    #[test]
+    fn test_comment_create_response_deserialization() {
+        let json = json!({
+            "commentCreate": {
+                "success": true,
+                "comment": {"id": "comment-1", "url": "https://linear.app/eng-1#comment-1"}
+            }
+        });
+        let response: CommentCreateResponse = serde_json::from_value(json).unwrap();
+        assert!(response.comment_create.success);
+        assert_eq!(response.comment_create.comment.unwrap().id, "comment-1");
+    }
+
     #[test]
     fn test_graphql_response_deserialization() {
         let json = json!({
@@ -0,0 +1,150 @@
+//! Helpers for `attach` and `view --download-assets`: content types, the
+//! markdown that embeds an uploaded asset, and finding assets in issue text.
+
+use std::path::Path;
+
+const CONTENT_TYPES: &[(&str, &str)] = &[
+    ("png", "image/png"),
+    ("jpg", "image/jpeg"),
+    ("jpeg", "image/jpeg"),
+    ("gif", "image/gif"),
+    ("webp", "image/webp"),
+    ("svg", "image/svg+xml"),
+    ("pdf", "application/pdf"),
+    ("json", "application/json"),
+    ("zip", "application/zip"),
+    ("gz", "application/gzip"),
+    ("txt", "text/plain"),
+    ("log", "text/plain"),
+    ("md", "text/markdown"),
+    ("csv", "text/csv"),
+    ("html", "text/html"),
+    ("mp4", "video/mp4"),
+    ("mov", "video/quicktime"),
+    ("webm", "video/webm"),
+];
+
+pub fn content_type(path: &Path) -> &'static str {
+    let extension = path
+        .extension()
+        .and_then(|ext| ext.to_str())
+        .map(|ext| ext.to_ascii_lowercase())
+        .unwrap_or_default();
+    CONTENT_TYPES
+        .iter()
+        .find(|(ext, _)| *ext == extension)
+        .map_or("application/octet-stream", |(_, content_type)| content_type)
+}
+
+/// Images are embedded inline; anything else becomes a link.
+pub fn asset_markdown(name: &str, content_type: &str, asset_url: &str) -> String {
+    if content_type.starts_with("image/") {
+        format!("![{}]({})", name, asset_url)
+    } else {
+        format!("[{}]({})", name, asset_url)
+    }
+}
+
+const ASSET_HOST: &str = "https://uploads.linear.app/";
+
+/// Every distinct `uploads.linear.app` URL in `text`, in order of appearance.
+pub fn asset_urls(text: &str) -> Vec<String> {
+    let mut urls: Vec<String> = Vec::new();
+    let mut rest = text;
+    while let Some(start) = rest.find(ASSET_HOST) {
+        let candidate = &rest[start..];
+        let end = candidate
+            .find(|c: char| c.is_whitespace() || matches!(c, ')' | ']' | '"' | '\'' | '<' | '>'))
+            .unwrap_or(candidate.len());
+        let url = &candidate[..end];
+        if url.len() > ASSET_HOST.len() && !urls.iter().any(|seen| seen == url) {
+            urls.push(url.to_string());
+        }
+        rest = &candidate[end..];
+    }
+    urls
+}
+
+/// Local file name for a downloaded asset: the last URL segment, made safe for
+/// the file system, with an extension from the content type when it has none.
+pub fn asset_file_name(url: &str, content_type: Option<&str>) -> String {
+    let segment = url
+        .split(['?', '#'])
+        .next()
+        .unwrap_or(url)
+        .trim_end_matches('/')
+        .rsplit('/')
+        .next()
+        .unwrap_or("asset");
+    let mut name: String = segment
+        .chars()
+        .map(|c| if c.is_ascii_alphanumeric() || matches!(c, '.' | '-' | '_') { c } else { '_' })
+        .collect();
+    if name.is_empty() || name.starts_with('.') {
+        name.insert_str(0, "asset");
+    }
+
+    if !name.contains('.')
+        && let Some(content_type) = content_type
+    {
+        let content_type = content_type.split(';').next().unwrap_or("").trim();
+        if let Some((extension, _)) = CONTENT_TYPES.iter().find(|(_, known)| *known == content_type) {
+            name.push('.');
+            name.push_str(extension);
+        }
+    }
+    name
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_content_type_from_extension() {
+        assert_eq!(content_type(Path::new("shot.PNG")), "image/png");
+        assert_eq!(content_type(Path::new("logs/app.log")), "text/plain");
+        assert_eq!(content_type(Path::new("core")), "application/octet-stream");
+    }
+
+    #[test]
+    fn test_asset_urls_in_markdown() {
+        let text = "See ![shot](https://uploads.linear.app/org/a1/b2)\n\
+                    and [log](https://uploads.linear.app/org/c3/d4?x=1) \
+                    again https://uploads.linear.app/org/a1/b2 but not https://example.com/x";
+        assert_eq!(
+            asset_urls(text),
+            vec![
+                "https://uploads.linear.app/org/a1/b2",
+                "https://uploads.linear.app/org/c3/d4?x=1"
+            ]
+        );
+        assert!(asset_urls("https://uploads.linear.app/ nothing").is_empty());
+    }
+
+    #[test]
+    fn test_asset_file_name() {
+        assert_eq!(
+            asset_file_name("https://uploads.linear.app/org/a1/b2c3", Some("image/png")),
+            "b2c3.png"
+        );
+        assert_eq!(
+            asset_file_name("https://uploads.linear.app/org/a1/report.pdf?sig=1", Some("application/pdf")),
+            "report.pdf"
+        );
+        assert_eq!(asset_file_name("https://uploads.linear.app/org/a1/x%20y", None), "x_20y");
+        assert_eq!(asset_file_name("https://uploads.linear.app/org/a1/..", None), "asset..");
+    }
+
+    #[test]
+    fn test_asset_markdown() {
+        assert_eq!(
+            asset_markdown("shot.png", "image/png", "https://uploads.linear.app/a"),
+            "![shot.png](https://uploads.linear.app/a)"
+        );
+        assert_eq!(
+            asset_markdown("app.log", "text/plain", "https://uploads.linear.app/b"),
+            "[app.log](https://uploads.linear.app/b)"
+        );
+    }
+}